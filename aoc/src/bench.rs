@@ -0,0 +1,274 @@
+use crate::alloc;
+use anyhow::{anyhow, Result};
+use common::{Part, Solution};
+use serde::Serialize;
+use std::{
+    hint::black_box,
+    panic::{self, AssertUnwindSafe},
+    time::{Duration, Instant},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Phase {
+    fn name(self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::Part1 => "part1",
+            Phase::Part2 => "part2",
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct PhaseReport {
+    pub phase: Phase,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+    /// Allocations made by a single run (the median across runs).
+    pub allocations: u64,
+    pub allocated_bytes: u64,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DayReport {
+    pub day: u8,
+    pub phases: Vec<PhaseReport>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Report {
+    pub iterations: usize,
+    pub days: Vec<DayReport>,
+}
+
+struct Sample {
+    elapsed: Duration,
+    allocations: u64,
+    allocated_bytes: u64,
+}
+
+fn measure<T>(f: impl FnOnce() -> T) -> (T, Sample) {
+    let (allocations, allocated_bytes) = alloc::snapshot();
+    let start = Instant::now();
+    let result = black_box(f());
+    let elapsed = start.elapsed();
+    let (allocations_after, allocated_bytes_after) = alloc::snapshot();
+    let sample = Sample {
+        elapsed,
+        allocations: allocations_after - allocations,
+        allocated_bytes: allocated_bytes_after - allocated_bytes,
+    };
+    (result, sample)
+}
+
+/// Runs one phase, turning a panic into an error so that a day failing on
+/// its input is reported like any other failure rather than ending the run.
+fn catch<T>(f: impl FnOnce() -> Result<T>) -> Result<T> {
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|payload| {
+        let message = match payload.downcast_ref::<&str>() {
+            Some(message) => message.to_string(),
+            None => match payload.downcast_ref::<String>() {
+                Some(message) => message.clone(),
+                None => "unknown cause".to_string(),
+            },
+        };
+        Err(anyhow!("panicked: {}", message))
+    })
+}
+
+fn median(values: &mut [u64]) -> u64 {
+    values.sort_unstable();
+    let mid = values.len() / 2;
+    if values.len().is_multiple_of(2) {
+        (values[mid - 1] + values[mid]) / 2
+    } else {
+        values[mid]
+    }
+}
+
+fn summarise(phase: Phase, samples: &[Sample]) -> PhaseReport {
+    let mut times: Vec<u64> = samples
+        .iter()
+        .map(|s| s.elapsed.as_nanos() as u64)
+        .collect();
+    let mut allocations: Vec<u64> = samples.iter().map(|s| s.allocations).collect();
+    let mut allocated_bytes: Vec<u64> = samples.iter().map(|s| s.allocated_bytes).collect();
+    PhaseReport {
+        phase,
+        min_ns: times.iter().copied().min().unwrap_or(0),
+        max_ns: times.iter().copied().max().unwrap_or(0),
+        median_ns: median(&mut times),
+        allocations: median(&mut allocations),
+        allocated_bytes: median(&mut allocated_bytes),
+        error: None,
+    }
+}
+
+fn failed(phase: Phase, error: anyhow::Error) -> PhaseReport {
+    PhaseReport {
+        phase,
+        min_ns: 0,
+        median_ns: 0,
+        max_ns: 0,
+        allocations: 0,
+        allocated_bytes: 0,
+        error: Some(error.to_string()),
+    }
+}
+
+/// Times parsing and both parts of a solution, running each phase
+/// `iterations` times. A phase that fails or panics is reported once and not
+/// retried.
+pub fn bench_solution<S: Solution>(input: &str, iterations: usize) -> Vec<PhaseReport> {
+    let mut samples = Vec::with_capacity(iterations);
+    let mut parsed = None;
+    for _ in 0..iterations {
+        let (result, sample) = measure(|| catch(|| S::parse(input)));
+        match result {
+            Ok(p) => {
+                samples.push(sample);
+                // the previous value is dropped outside of the measurement
+                parsed = Some(p);
+            }
+            Err(e) => return vec![failed(Phase::Parse, e)],
+        }
+    }
+    let mut reports = vec![summarise(Phase::Parse, &samples)];
+    let Some(parsed) = parsed else {
+        return reports;
+    };
+
+    for (phase, part) in [(Phase::Part1, Part::One), (Phase::Part2, Part::Two)] {
+        let mut samples = Vec::with_capacity(iterations);
+        let mut error = None;
+        for _ in 0..iterations {
+            let (result, sample) = measure(|| catch(|| S::solve(&parsed, part)));
+            if let Err(e) = result {
+                error = Some(e);
+                break;
+            }
+            samples.push(sample);
+        }
+        reports.push(match error {
+            Some(e) => failed(phase, e),
+            None => summarise(phase, &samples),
+        });
+    }
+    reports
+}
+
+fn format_ns(ns: u64) -> String {
+    format!("{:.1?}", Duration::from_nanos(ns))
+}
+
+impl Report {
+    pub fn print(&self) {
+        println!(
+            "{:>3}  {:<6} {:>12} {:>12} {:>12} {:>10} {:>12}",
+            "day", "phase", "min", "median", "max", "allocs", "bytes"
+        );
+        for day in &self.days {
+            for phase in &day.phases {
+                match &phase.error {
+                    Some(error) => println!(
+                        "{:>3}  {:<6} failed: {}",
+                        day.day,
+                        phase.phase.name(),
+                        error
+                    ),
+                    None => println!(
+                        "{:>3}  {:<6} {:>12} {:>12} {:>12} {:>10} {:>12}",
+                        day.day,
+                        phase.phase.name(),
+                        format_ns(phase.min_ns),
+                        format_ns(phase.median_ns),
+                        format_ns(phase.max_ns),
+                        phase.allocations,
+                        phase.allocated_bytes
+                    ),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use common::Answer;
+
+    #[test]
+    fn test_median() {
+        assert_eq!(median(&mut [5, 1, 3]), 3);
+        assert_eq!(median(&mut [4, 1, 3, 2]), 2);
+    }
+
+    struct Allocating;
+
+    impl Solution for Allocating {
+        type Parsed = Vec<u64>;
+
+        fn parse(input: &str) -> Result<Self::Parsed> {
+            Ok(input.split(',').map(|n| n.parse().unwrap()).collect())
+        }
+
+        fn part1(parsed: &Self::Parsed) -> Result<Answer> {
+            let boxed: Vec<Box<u64>> = parsed.iter().map(|&n| Box::new(n)).collect();
+            Ok(Answer::from(boxed.len()))
+        }
+
+        fn part2(_: &Self::Parsed) -> Result<Answer> {
+            Err(anyhow!("no part 2"))
+        }
+    }
+
+    #[test]
+    fn test_bench_solution_counts_allocations() {
+        let reports = bench_solution::<Allocating>("1,2,3,4", 3);
+        assert_eq!(reports.len(), 3);
+
+        assert_eq!(reports[0].phase, Phase::Parse);
+        assert!(reports[0].allocations >= 1);
+        // four boxes plus the vector holding them
+        assert!(reports[1].allocations >= 5);
+        assert!(reports[1].min_ns <= reports[1].median_ns);
+        assert!(reports[1].median_ns <= reports[1].max_ns);
+        assert_eq!(reports[2].error.as_deref(), Some("no part 2"));
+    }
+
+    struct Panicking;
+
+    impl Solution for Panicking {
+        type Parsed = Vec<usize>;
+
+        fn parse(input: &str) -> Result<Self::Parsed> {
+            Ok(input.split(',').map(|n| n.parse().unwrap()).collect())
+        }
+
+        fn part1(parsed: &Self::Parsed) -> Result<Answer> {
+            Ok(Answer::from(parsed[0]))
+        }
+
+        fn part2(parsed: &Self::Parsed) -> Result<Answer> {
+            Ok(Answer::from(parsed.len()))
+        }
+    }
+
+    #[test]
+    fn test_bench_solution_reports_panics() {
+        let reports = bench_solution::<Panicking>("", 2);
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].phase, Phase::Parse);
+        let error = reports[0].error.as_deref().unwrap();
+        assert!(error.starts_with("panicked: "), "{}", error);
+    }
+}
@@ -0,0 +1,90 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Per-user settings read from `~/.config/aoc/config.toml` (or whatever
+/// `AOC_CONFIG` points at).
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// Directory holding `dayN.txt` (or `dayN/dayN.txt`) puzzle inputs.
+    pub input_dir: Option<PathBuf>,
+    /// Session cookie used to download inputs.
+    pub session: Option<String>,
+    /// Where downloaded inputs are kept, defaults to the user cache dir.
+    pub cache_dir: Option<PathBuf>,
+    /// Site to download from, overridable so tests can use a local server.
+    pub base_url: Option<String>,
+}
+
+impl Config {
+    pub fn path() -> Option<PathBuf> {
+        match env::var_os("AOC_CONFIG") {
+            Some(path) => Some(PathBuf::from(path)),
+            None => dirs::config_dir().map(|dir| dir.join("aoc").join("config.toml")),
+        }
+    }
+
+    /// Loads the config file, returning `None` when it doesn't exist.
+    pub fn load_from(path: &Path) -> Result<Option<Config>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read config {}", path.display()))?;
+        let config = toml::from_str(&text)
+            .with_context(|| format!("Failed to parse config {}", path.display()))?;
+        Ok(Some(config))
+    }
+}
+
+/// The config file (if any) together with the environment variables that
+/// override it.
+pub struct Settings {
+    pub config_path: Option<PathBuf>,
+    pub config: Option<Config>,
+}
+
+impl Settings {
+    pub fn load() -> Result<Settings> {
+        let config_path = Config::path();
+        let config = match &config_path {
+            Some(path) => Config::load_from(path)?,
+            None => None,
+        };
+        Ok(Settings {
+            config_path,
+            config,
+        })
+    }
+
+    fn config_value<T: Clone>(&self, field: impl Fn(&Config) -> &Option<T>) -> Option<T> {
+        self.config.as_ref().and_then(|c| field(c).clone())
+    }
+
+    pub fn session(&self) -> Option<String> {
+        env::var("AOC_SESSION")
+            .ok()
+            .or_else(|| self.config_value(|c| &c.session))
+            .map(|s| s.trim().to_string())
+    }
+
+    pub fn cache_dir(&self) -> Option<PathBuf> {
+        env::var_os("AOC_CACHE_DIR")
+            .map(PathBuf::from)
+            .or_else(|| self.config_value(|c| &c.cache_dir))
+            .or_else(|| dirs::cache_dir().map(|dir| dir.join("aoc")))
+    }
+
+    pub fn base_url(&self) -> String {
+        env::var("AOC_BASE_URL")
+            .ok()
+            .or_else(|| self.config_value(|c| &c.base_url))
+            .unwrap_or_else(|| DEFAULT_BASE_URL.to_string())
+    }
+}
@@ -0,0 +1,115 @@
+use anyhow::Result;
+use common::{Answer, Part, Solution};
+
+/// Expands to `Ok($($f)::+::<DayN>(args..))` for the solution registered for
+/// `$day`, or an error if no such day exists.
+macro_rules! with_solution {
+    ($day:expr, $($f:ident)::+($($arg:expr),* $(,)?)) => {
+        match $day {
+            1 => Ok($($f)::+::<day1::Day1>($($arg),*)),
+            2 => Ok($($f)::+::<day2::Day2>($($arg),*)),
+            3 => Ok($($f)::+::<day3::Day3>($($arg),*)),
+            4 => Ok($($f)::+::<day4::Day4>($($arg),*)),
+            5 => Ok($($f)::+::<day5::Day5>($($arg),*)),
+            6 => Ok($($f)::+::<day6::Day6>($($arg),*)),
+            7 => Ok($($f)::+::<day7::Day7>($($arg),*)),
+            8 => Ok($($f)::+::<day8::Day8>($($arg),*)),
+            9 => Ok($($f)::+::<day9::Day9>($($arg),*)),
+            10 => Ok($($f)::+::<day10::Day10>($($arg),*)),
+            day => Err(anyhow::anyhow!("No solution for day {}", day)),
+        }
+    };
+}
+
+pub(crate) use with_solution;
+
+fn solve_parts<S: Solution>(input: &str, parts: &[Part]) -> Result<Vec<Answer>> {
+    let parsed = S::parse(input)?;
+    parts.iter().map(|&part| S::solve(&parsed, part)).collect()
+}
+
+/// Parses the input once and answers each of the requested parts in order.
+pub fn solve(day: u8, input: &str, parts: &[Part]) -> Result<Vec<Answer>> {
+    with_solution!(day, solve_parts(input, parts))?
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::{fs, path::PathBuf};
+
+    /// Each day lists its example inputs and their published answers in
+    /// `dayN/answers.txt`, one `input part1 part2` row per example, with `-`
+    /// standing in for a part the example has no answer for.
+    fn golden_answers(day: u8) -> Vec<(PathBuf, [Option<Answer>; 2])> {
+        let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join(format!("day{}", day));
+        let path = dir.join("answers.txt");
+        let text = fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("Failed to read {}: {}", path.display(), e));
+
+        let mut examples = Vec::new();
+        for (line_no, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() != 3 {
+                panic!(
+                    "{}:{}: expected `input part1 part2`, found {:?}",
+                    path.display(),
+                    line_no + 1,
+                    line
+                );
+            }
+            let expected = |field: &str| match field {
+                "-" => None,
+                answer => Some(answer.parse().unwrap()),
+            };
+            examples.push((
+                dir.join(fields[0]),
+                [expected(fields[1]), expected(fields[2])],
+            ));
+        }
+        examples
+    }
+
+    #[test]
+    fn test_golden_answers() {
+        let mut failures = Vec::new();
+
+        for day in 1..=10 {
+            for (input_path, expected) in golden_answers(day) {
+                let input = fs::read_to_string(&input_path).unwrap();
+                for (part, expected) in Part::ALL.into_iter().zip(expected) {
+                    let Some(expected) = expected else {
+                        continue;
+                    };
+                    let outcome = solve(day, &input, &[part]).map(|mut a| a.remove(0));
+                    match outcome {
+                        Ok(answer) if answer == expected => {}
+                        Ok(answer) => failures.push(format!(
+                            "day {} part {} on {}: expected {}, got {}",
+                            day,
+                            part,
+                            input_path.display(),
+                            expected,
+                            answer
+                        )),
+                        Err(e) => failures.push(format!(
+                            "day {} part {} on {}: {}",
+                            day,
+                            part,
+                            input_path.display(),
+                            e
+                        )),
+                    }
+                }
+            }
+        }
+
+        assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+    }
+}
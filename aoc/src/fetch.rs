@@ -0,0 +1,201 @@
+use crate::http::HttpBackend;
+use anyhow::{anyhow, Context, Result};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Downloaded puzzle inputs, stored as `<dir>/<year>/dayN.txt`.
+pub struct InputCache {
+    dir: PathBuf,
+}
+
+impl InputCache {
+    pub fn new(dir: PathBuf) -> Self {
+        InputCache { dir }
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("day{}.txt", day))
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Result<Option<String>> {
+        let path = self.path(year, day);
+        if !path.is_file() {
+            return Ok(None);
+        }
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read cached input {}", path.display()))?;
+        Ok(Some(content))
+    }
+
+    pub fn store(&self, year: u16, day: u8, content: &str) -> Result<PathBuf> {
+        let path = self.path(year, day);
+        write_atomically(&path, content)?;
+        Ok(path)
+    }
+}
+
+/// Writes through a temporary file so an interrupted download never leaves
+/// a truncated input behind in the cache.
+fn write_atomically(path: &Path, content: &str) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    let tmp = path.with_extension("tmp");
+    fs::write(&tmp, content).with_context(|| format!("Failed to write {}", tmp.display()))?;
+    fs::rename(&tmp, path).with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(())
+}
+
+pub struct InputFetcher<B: HttpBackend> {
+    backend: B,
+    base_url: String,
+    session: Option<String>,
+    cache: InputCache,
+}
+
+impl<B: HttpBackend> InputFetcher<B> {
+    pub fn new(backend: B, base_url: &str, session: Option<String>, cache: InputCache) -> Self {
+        InputFetcher {
+            backend,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            cache,
+        }
+    }
+
+    /// Returns the day's input, downloading it only if it isn't cached yet.
+    pub fn input(&self, year: u16, day: u8) -> Result<String> {
+        if let Some(content) = self.cache.get(year, day)? {
+            return Ok(content);
+        }
+
+        let session = self.session.as_deref().ok_or_else(|| {
+            anyhow!(
+                "Input for {} day {} isn't cached and no session token is set \
+                 (set $AOC_SESSION or `session` in the config file)",
+                year,
+                day
+            )
+        })?;
+
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        let response = self.backend.get(&url, session)?;
+        if response.status != 200 {
+            return Err(anyhow!(
+                "GET {} returned {}: {}",
+                url,
+                response.status,
+                response.body.trim()
+            ));
+        }
+
+        self.cache.store(year, day, &response.body)?;
+        Ok(response.body)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::http::{HttpResponse, UreqBackend};
+    use crate::stub_server::StubServer;
+    use std::cell::RefCell;
+
+    fn scratch_cache(name: &str) -> InputCache {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        InputCache::new(dir)
+    }
+
+    struct CannedBackend {
+        requests: RefCell<Vec<(String, String)>>,
+    }
+
+    impl HttpBackend for CannedBackend {
+        fn get(&self, url: &str, session: &str) -> Result<HttpResponse> {
+            self.requests
+                .borrow_mut()
+                .push((url.to_string(), session.to_string()));
+            Ok(HttpResponse {
+                status: 200,
+                body: "1   2\n".to_string(),
+            })
+        }
+
+        fn post_form(&self, _: &str, _: &str, _: &[(&str, &str)]) -> Result<HttpResponse> {
+            unreachable!("fetching never posts")
+        }
+    }
+
+    #[test]
+    fn test_fetches_once_then_uses_cache() {
+        let backend = CannedBackend {
+            requests: RefCell::new(Vec::new()),
+        };
+        let fetcher = InputFetcher::new(
+            backend,
+            "https://example.com/",
+            Some("abc".to_string()),
+            scratch_cache("once"),
+        );
+
+        assert_eq!(fetcher.input(2024, 1).unwrap(), "1   2\n");
+        assert_eq!(fetcher.input(2024, 1).unwrap(), "1   2\n");
+
+        let requests = fetcher.backend.requests.borrow();
+        assert_eq!(
+            *requests,
+            vec![(
+                "https://example.com/2024/day/1/input".to_string(),
+                "abc".to_string()
+            )]
+        );
+        assert!(fetcher.cache.path(2024, 1).ends_with("2024/day1.txt"));
+    }
+
+    #[test]
+    fn test_missing_session_is_an_error() {
+        let backend = CannedBackend {
+            requests: RefCell::new(Vec::new()),
+        };
+        let fetcher = InputFetcher::new(backend, "http://unused", None, scratch_cache("nosession"));
+        let err = fetcher.input(2024, 5).unwrap_err().to_string();
+        assert!(err.contains("no session token"));
+    }
+
+    #[test]
+    fn test_against_stub_server() {
+        let server = StubServer::start(|request| {
+            if request.method == "GET"
+                && request.path == "/2024/day/6/input"
+                && request.cookie() == Some("session=tok")
+            {
+                (200, "....#.....\n".to_string())
+            } else {
+                (
+                    400,
+                    "Please don't repeatedly request this endpoint".to_string(),
+                )
+            }
+        });
+        let fetcher = InputFetcher::new(
+            UreqBackend::new(),
+            &server.url(),
+            Some("tok".to_string()),
+            scratch_cache("stub"),
+        );
+
+        assert_eq!(fetcher.input(2024, 6).unwrap(), "....#.....\n");
+        assert_eq!(fetcher.input(2024, 6).unwrap(), "....#.....\n");
+        assert_eq!(server.request_count(), 1);
+
+        let err = fetcher.input(2024, 7).unwrap_err().to_string();
+        assert!(err.contains("returned 400"));
+        assert!(fetcher.cache.get(2024, 7).unwrap().is_none());
+    }
+}
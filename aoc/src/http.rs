@@ -0,0 +1,66 @@
+use anyhow::{Context, Result};
+
+const USER_AGENT: &str = "github.com/jamesadevine/advent-of-code-2024";
+
+pub struct HttpResponse {
+    pub status: u16,
+    pub body: String,
+}
+
+/// The transport used to talk to the puzzle site. Everything above this
+/// trait only deals in URLs and response bodies, so tests can swap in a
+/// canned backend or point the real one at a local stub server.
+pub trait HttpBackend {
+    fn get(&self, url: &str, session: &str) -> Result<HttpResponse>;
+    fn post_form(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<HttpResponse>;
+}
+
+pub struct UreqBackend {
+    agent: ureq::Agent,
+}
+
+impl UreqBackend {
+    pub fn new() -> Self {
+        let agent = ureq::Agent::config_builder()
+            .http_status_as_error(false)
+            .build()
+            .into();
+        UreqBackend { agent }
+    }
+}
+
+impl Default for UreqBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HttpBackend for UreqBackend {
+    fn get(&self, url: &str, session: &str) -> Result<HttpResponse> {
+        let mut response = self
+            .agent
+            .get(url)
+            .header("Cookie", format!("session={}", session))
+            .header("User-Agent", USER_AGENT)
+            .call()
+            .with_context(|| format!("GET {} failed", url))?;
+        Ok(HttpResponse {
+            status: response.status().as_u16(),
+            body: response.body_mut().read_to_string()?,
+        })
+    }
+
+    fn post_form(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<HttpResponse> {
+        let mut response = self
+            .agent
+            .post(url)
+            .header("Cookie", format!("session={}", session))
+            .header("User-Agent", USER_AGENT)
+            .send_form(form.iter().copied())
+            .with_context(|| format!("POST {} failed", url))?;
+        Ok(HttpResponse {
+            status: response.status().as_u16(),
+            body: response.body_mut().read_to_string()?,
+        })
+    }
+}
@@ -0,0 +1,290 @@
+use crate::{config::Settings, fetch::InputCache, YEAR};
+use anyhow::{anyhow, Context, Result};
+use std::{
+    env,
+    fs::{self, File},
+    io::{self, BufRead, BufReader, IsTerminal, Read},
+    path::{Path, PathBuf},
+};
+
+/// Finds the puzzle input for a day by checking, in order: the `--input`
+/// argument, `$AOC_INPUT_DIR`, the `input_dir` from the user's config file,
+/// the download cache and finally stdin, if something was piped to it.
+pub struct InputResolver {
+    arg: Option<PathBuf>,
+    env_dir: Option<PathBuf>,
+    config_path: Option<PathBuf>,
+    config_dir: Option<PathBuf>,
+    cache: Option<InputCache>,
+    stdin: Stdin,
+}
+
+/// Whether stdin can stand in for an input that wasn't found anywhere else.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Stdin {
+    Terminal,
+    /// Redirected, though possibly from something empty like `/dev/null`.
+    Redirected,
+    /// Only read when asked for with `--input -`.
+    Ignored,
+}
+
+/// Where an input was found, before any of it is read.
+enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputResolver {
+    pub fn new(arg: Option<PathBuf>, settings: &Settings) -> Self {
+        InputResolver {
+            arg,
+            env_dir: env::var_os("AOC_INPUT_DIR").map(PathBuf::from),
+            config_path: settings.config_path.clone(),
+            config_dir: settings.config.as_ref().and_then(|c| c.input_dir.clone()),
+            cache: settings.cache_dir().map(InputCache::new),
+            stdin: if io::stdin().is_terminal() {
+                Stdin::Terminal
+            } else {
+                Stdin::Redirected
+            },
+        }
+    }
+
+    /// Never falls back to stdin, for commands that resolve an input for
+    /// each of several days and so can't give them all the one on stdin.
+    pub fn without_stdin(self) -> Self {
+        InputResolver {
+            stdin: Stdin::Ignored,
+            ..self
+        }
+    }
+
+    pub fn resolve(&self, day: u8) -> Result<String> {
+        match self.locate(day)? {
+            Source::Stdin => read_stdin(),
+            Source::File(path) => fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read input {}", path.display())),
+        }
+    }
+
+    /// Like `resolve`, but hands back a reader rather than the whole input,
+    /// for inputs too large to hold in memory.
+    pub fn open(&self, day: u8) -> Result<Box<dyn BufRead>> {
+        match self.locate(day)? {
+            Source::Stdin => Ok(Box::new(io::stdin().lock())),
+            Source::File(path) => {
+                let file = File::open(&path)
+                    .with_context(|| format!("Failed to open input {}", path.display()))?;
+                Ok(Box::new(BufReader::new(file)))
+            }
+        }
+    }
+
+    fn locate(&self, day: u8) -> Result<Source> {
+        let mut tried = Vec::new();
+
+        if let Some(arg) = &self.arg {
+            if arg.as_os_str() == "-" {
+                return Ok(Source::Stdin);
+            }
+            // an explicit path is never second-guessed by the fallbacks
+            return Ok(Source::File(arg.clone()));
+        }
+        tried.push("--input: not given".to_string());
+
+        match &self.env_dir {
+            Some(dir) => {
+                if let Some(path) = find_in_dir(dir, day, "$AOC_INPUT_DIR", &mut tried) {
+                    return Ok(Source::File(path));
+                }
+            }
+            None => tried.push("$AOC_INPUT_DIR: not set".to_string()),
+        }
+
+        match (&self.config_path, &self.config_dir) {
+            (Some(path), Some(dir)) => {
+                let source = format!("input_dir in {}", path.display());
+                if let Some(path) = find_in_dir(dir, day, &source, &mut tried) {
+                    return Ok(Source::File(path));
+                }
+            }
+            (Some(path), None) if path.exists() => {
+                tried.push(format!("{}: no input_dir set", path.display()))
+            }
+            (Some(path), None) => tried.push(format!("{}: not found", path.display())),
+            (None, _) => tried.push("config file: no config directory".to_string()),
+        }
+
+        match &self.cache {
+            Some(cache) => {
+                let path = cache.path(YEAR, day);
+                if path.is_file() {
+                    return Ok(Source::File(path));
+                }
+                tried.push(format!(
+                    "{} (cache): not found, run `aoc fetch --day {}`",
+                    path.display(),
+                    day
+                ));
+            }
+            None => tried.push("cache: no cache directory".to_string()),
+        }
+
+        match self.stdin {
+            Stdin::Redirected if stdin_has_data()? => return Ok(Source::Stdin),
+            Stdin::Redirected => tried.push("stdin: empty".to_string()),
+            Stdin::Terminal => tried.push("stdin: is a terminal".to_string()),
+            Stdin::Ignored => tried.push("stdin: only read with --input -".to_string()),
+        }
+
+        Err(anyhow!(
+            "Could not find input for day {}, tried:\n  {}",
+            day,
+            tried.join("\n  ")
+        ))
+    }
+}
+
+/// Candidate file names for a day's input inside an input directory, both
+/// flat (`day6.txt`) and matching this repository's layout (`day6/day6.txt`).
+fn candidates(dir: &Path, day: u8) -> [PathBuf; 2] {
+    let name = format!("day{}.txt", day);
+    [dir.join(&name), dir.join(format!("day{}", day)).join(&name)]
+}
+
+fn find_in_dir(dir: &Path, day: u8, source: &str, tried: &mut Vec<String>) -> Option<PathBuf> {
+    for path in candidates(dir, day) {
+        if path.is_file() {
+            return Some(path);
+        }
+        tried.push(format!("{} ({}): not found", path.display(), source));
+    }
+    None
+}
+
+/// Waits for the first of stdin to arrive, without consuming it, so that
+/// an empty stdin such as `/dev/null` isn't taken for an empty input.
+fn stdin_has_data() -> Result<bool> {
+    let mut stdin = io::stdin().lock();
+    let buffered = stdin
+        .fill_buf()
+        .context("Failed to read input from stdin")?;
+    Ok(!buffered.is_empty())
+}
+
+fn read_stdin() -> Result<String> {
+    let mut content = String::new();
+    io::stdin()
+        .read_to_string(&mut content)
+        .context("Failed to read input from stdin")?;
+    Ok(content)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::config::Config;
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-input-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn resolver(
+        arg: Option<PathBuf>,
+        env_dir: Option<PathBuf>,
+        config_path: Option<PathBuf>,
+    ) -> InputResolver {
+        let config_dir = config_path
+            .as_deref()
+            .and_then(|path| Config::load_from(path).unwrap())
+            .and_then(|config| config.input_dir);
+        InputResolver {
+            arg,
+            env_dir,
+            config_path,
+            config_dir,
+            cache: None,
+            stdin: Stdin::Terminal,
+        }
+    }
+
+    #[test]
+    fn test_explicit_argument_wins() {
+        let dir = scratch_dir("arg");
+        fs::write(dir.join("mine.txt"), "from arg").unwrap();
+        fs::write(dir.join("day6.txt"), "from env").unwrap();
+
+        let r = resolver(Some(dir.join("mine.txt")), Some(dir.clone()), None);
+        assert_eq!(r.resolve(6).unwrap(), "from arg");
+    }
+
+    #[test]
+    fn test_open_reads_the_same_input() {
+        let dir = scratch_dir("open");
+        fs::write(dir.join("day1.txt"), "3   4\n4   3\n").unwrap();
+
+        let r = resolver(None, Some(dir), None);
+        let lines: Vec<String> = r.open(1).unwrap().lines().map(Result::unwrap).collect();
+        assert_eq!(lines.join("\n") + "\n", r.resolve(1).unwrap());
+    }
+
+    #[test]
+    fn test_env_dir_supports_repo_layout() {
+        let dir = scratch_dir("env");
+        fs::create_dir_all(dir.join("day6")).unwrap();
+        fs::write(dir.join("day6").join("day6.txt"), "nested").unwrap();
+
+        let r = resolver(None, Some(dir), None);
+        assert_eq!(r.resolve(6).unwrap(), "nested");
+    }
+
+    #[test]
+    fn test_config_input_dir() {
+        let dir = scratch_dir("config");
+        let config = dir.join("config.toml");
+        fs::write(&config, format!("input_dir = {:?}\n", dir.join("inputs"))).unwrap();
+        fs::create_dir_all(dir.join("inputs")).unwrap();
+        fs::write(dir.join("inputs").join("day2.txt"), "configured").unwrap();
+
+        let r = resolver(None, None, Some(config));
+        assert_eq!(r.resolve(2).unwrap(), "configured");
+    }
+
+    #[test]
+    fn test_error_lists_every_location() {
+        let dir = scratch_dir("missing");
+        let config = dir.join("config.toml");
+
+        let r = resolver(None, Some(dir.clone()), Some(config.clone()));
+        let err = r.resolve(3).unwrap_err().to_string();
+
+        assert!(err.contains("--input: not given"));
+        assert!(err.contains(&dir.join("day3.txt").display().to_string()));
+        assert!(err.contains(&dir.join("day3").join("day3.txt").display().to_string()));
+        assert!(err.contains(&format!("{}: not found", config.display())));
+        assert!(err.contains("cache: no cache directory"));
+        assert!(err.contains("stdin: is a terminal"));
+    }
+
+    #[test]
+    fn test_without_stdin() {
+        let r = resolver(None, None, None).without_stdin();
+        let err = r.resolve(4).unwrap_err().to_string();
+        assert!(err.contains("stdin: only read with --input -"));
+    }
+
+    #[test]
+    fn test_falls_back_to_cache() {
+        let dir = scratch_dir("cache");
+        let cache = InputCache::new(dir.clone());
+        cache.store(YEAR, 9, "12345").unwrap();
+
+        let mut r = resolver(None, Some(dir.join("empty")), None);
+        r.cache = Some(cache);
+        assert_eq!(r.resolve(9).unwrap(), "12345");
+    }
+}
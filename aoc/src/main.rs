@@ -0,0 +1,305 @@
+mod alloc;
+mod bench;
+mod config;
+mod days;
+mod fetch;
+mod http;
+mod input;
+#[cfg(test)]
+mod stub_server;
+mod submit;
+
+use alloc::CountingAllocator;
+use anyhow::{anyhow, Context, Result};
+use bench::{DayReport, Report};
+use clap::{Parser, Subcommand};
+use common::{Part, Solution};
+use config::Settings;
+use day1::{Day1, Stats, StreamOptions};
+use day2::{Day2, ExplainStyle, Explanation, Policy};
+use fetch::{InputCache, InputFetcher};
+use http::UreqBackend;
+use input::InputResolver;
+use std::{
+    env, fs,
+    io::{self, IsTerminal},
+    path::PathBuf,
+};
+use submit::{SubmissionLog, Submitter};
+
+pub const YEAR: u16 = 2024;
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2024 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a day's solution against a puzzle input
+    Run {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=10))]
+        day: u8,
+        /// Part to run; runs both parts when omitted
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input file, or `-` for stdin; falls back to `$AOC_INPUT_DIR`, the
+        /// config file's `input_dir`, the download cache and then piped stdin
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Run day 1, optionally streaming inputs too large to hold in memory
+    Day1 {
+        /// Input file, resolved the same way as for `run`
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Sort the lists in runs spilled to temporary files and merge them,
+        /// rather than reading the whole input into memory
+        #[arg(long)]
+        stream: bool,
+        /// Rows sorted in memory per run when streaming
+        #[arg(long, requires = "stream", value_parser = clap::value_parser!(u64).range(1..))]
+        run_len: Option<u64>,
+        /// Directory for the sorted runs; defaults to the system temp directory
+        #[arg(long, requires = "stream")]
+        temp_dir: Option<PathBuf>,
+        /// Also describe the two lists: their ranges, how far apart the pairs
+        /// are and which values make up the similarity score
+        #[arg(long, conflicts_with = "stream")]
+        stats: bool,
+        /// Number of similarity contributions listed by `--stats`
+        #[arg(long, requires = "stats", default_value_t = 10)]
+        top: usize,
+    },
+    /// Run day 2, optionally explaining the verdict on every report
+    Day2 {
+        /// Input file, resolved the same way as for `run`
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Print each report with the steps between its levels, the rules
+        /// they break and the levels the Problem Dampener removed
+        #[arg(long)]
+        explain: bool,
+        /// Print the explanations as JSON rather than annotated text
+        #[arg(long, requires = "explain")]
+        json: bool,
+        /// Check reports on several threads as they are read, rather than
+        /// reading the whole input first
+        #[arg(long, conflicts_with = "explain")]
+        stream: bool,
+        /// Threads checking reports when streaming; defaults to one per core
+        #[arg(long, requires = "stream", value_parser = clap::value_parser!(u64).range(1..))]
+        threads: Option<u64>,
+    },
+    /// Download a day's puzzle input into the local cache
+    Fetch {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        #[arg(long, default_value_t = YEAR)]
+        year: u16,
+    },
+    /// Submit a day's computed answer and record the verdict
+    Submit {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=10))]
+        day: u8,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Input file, resolved the same way as for `run`
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Time parsing and both parts, for one day or every day
+    Bench {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=10))]
+        day: Option<u8>,
+        /// Input file, only allowed together with `--day`
+        #[arg(long, requires = "day")]
+        input: Option<PathBuf>,
+        /// Number of times each phase is run
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        iterations: u32,
+        /// Also write the report as JSON to this file
+        #[arg(long)]
+        json: Option<PathBuf>,
+    },
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let settings = Settings::load()?;
+
+    match cli.command {
+        Command::Run { day, part, input } => {
+            let content = InputResolver::new(input, &settings).resolve(day)?;
+            let parts = match part {
+                Some(part) => vec![Part::try_from(part)?],
+                None => Part::ALL.to_vec(),
+            };
+            let answers = days::solve(day, &content, &parts)?;
+            for (part, answer) in parts.iter().zip(answers) {
+                println!("Day {} part {}: {}", day, part, answer);
+            }
+        }
+        Command::Day1 {
+            input,
+            stream,
+            run_len,
+            temp_dir,
+            stats,
+            top,
+        } => {
+            let resolver = InputResolver::new(input, &settings);
+            let answers = if stats {
+                let content = resolver.resolve(1)?;
+                let parsed = Day1::parse(&content)?;
+                let (left, right) = &parsed;
+                println!("{}", Stats::new(left, right, top));
+                vec![Day1::part1(&parsed)?, Day1::part2(&parsed)?]
+            } else if stream {
+                let mut options = StreamOptions::default();
+                if let Some(run_len) = run_len {
+                    options.run_len = run_len as usize;
+                }
+                if let Some(temp_dir) = temp_dir {
+                    options.temp_dir = temp_dir;
+                }
+                let totals = day1::stream_totals(resolver.open(1)?, &options)?;
+                vec![totals.distance.into(), totals.similarity.into()]
+            } else {
+                days::solve(1, &resolver.resolve(1)?, &Part::ALL)?
+            };
+            for (part, answer) in Part::ALL.iter().zip(answers) {
+                println!("Day 1 part {}: {}", part, answer);
+            }
+        }
+        Command::Day2 {
+            input,
+            explain,
+            json,
+            stream,
+            threads,
+        } => {
+            let resolver = InputResolver::new(input, &settings);
+            if stream {
+                let mut options = day2::StreamOptions::default();
+                if let Some(threads) = threads {
+                    options.threads = threads as usize;
+                }
+                let policies = [Policy::default(), Policy::dampened()];
+                let tallies = day2::stream_tally(resolver.open(2)?, &policies, &options)?;
+                for (part, tally) in Part::ALL.iter().zip(tallies) {
+                    println!("Day 2 part {}: {}", part, tally.safe);
+                }
+                return Ok(());
+            }
+
+            let content = resolver.resolve(2)?;
+            let reports = Day2::parse(&content)?;
+            if explain {
+                let policy = Policy::dampened();
+                let explanations: Vec<Explanation> = reports
+                    .iter()
+                    .map(|levels| policy.explain(levels))
+                    .collect();
+                if json {
+                    println!("{}", serde_json::to_string_pretty(&explanations)?);
+                    return Ok(());
+                }
+
+                // colour only when a person is likely to be reading it
+                let style = match io::stdout().is_terminal() && env::var_os("NO_COLOR").is_none() {
+                    true => ExplainStyle::Ansi,
+                    false => ExplainStyle::Plain,
+                };
+                for (idx, explanation) in explanations.iter().enumerate() {
+                    println!("Report {}:", idx + 1);
+                    println!("{}", explanation.render(style));
+                }
+            }
+            for part in Part::ALL {
+                println!("Day 2 part {}: {}", part, Day2::solve(&reports, part)?);
+            }
+        }
+        Command::Fetch { day, year } => {
+            let cache_dir = settings
+                .cache_dir()
+                .ok_or_else(|| anyhow!("No cache directory, set $AOC_CACHE_DIR"))?;
+            let cache = InputCache::new(cache_dir);
+            let path = cache.path(year, day);
+            let fetcher = InputFetcher::new(
+                UreqBackend::new(),
+                &settings.base_url(),
+                settings.session(),
+                cache,
+            );
+            fetcher.input(year, day)?;
+            println!("{}", path.display());
+        }
+        Command::Submit { day, part, input } => {
+            let part = Part::try_from(part)?;
+            let content = InputResolver::new(input, &settings).resolve(day)?;
+            let answer = days::solve(day, &content, &[part])?.remove(0);
+
+            let session = settings
+                .session()
+                .ok_or_else(|| anyhow!("No session token, set $AOC_SESSION"))?;
+            let cache_dir = settings
+                .cache_dir()
+                .ok_or_else(|| anyhow!("No cache directory, set $AOC_CACHE_DIR"))?;
+            let log =
+                SubmissionLog::new(cache_dir.join(YEAR.to_string()).join("submissions.jsonl"));
+            let submitter = Submitter::new(UreqBackend::new(), &settings.base_url(), session, log);
+
+            let verdict = submitter.submit(YEAR, day, part, &answer)?;
+            println!("Day {} part {}: {} is {}", day, part, answer, verdict);
+            if verdict.is_wrong() {
+                let bounds = submitter.bounds(YEAR, day, part)?;
+                if bounds.is_known() {
+                    println!("{}", bounds);
+                }
+            }
+        }
+        Command::Bench {
+            day,
+            input,
+            iterations,
+            json,
+        } => {
+            let iterations = iterations as usize;
+            let days = match day {
+                Some(day) => vec![day],
+                None => (1..=10).collect(),
+            };
+            let mut report = Report {
+                iterations,
+                days: Vec::new(),
+            };
+            for day in days {
+                let resolver = InputResolver::new(input.clone(), &settings).without_stdin();
+                let content = match resolver.resolve(day) {
+                    Ok(content) => content,
+                    Err(e) => {
+                        eprintln!("Skipping day {}: {}", day, e);
+                        continue;
+                    }
+                };
+                let phases =
+                    days::with_solution!(day, bench::bench_solution(&content, iterations))?;
+                report.days.push(DayReport { day, phases });
+            }
+
+            report.print();
+            if let Some(path) = json {
+                fs::write(&path, serde_json::to_string_pretty(&report)?)
+                    .with_context(|| format!("Failed to write {}", path.display()))?;
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,436 @@
+use crate::http::HttpBackend;
+use anyhow::{anyhow, Context, Result};
+use common::{Answer, Part};
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::Write,
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// What the site said about a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    RateLimited { wait: Option<String> },
+    AlreadySolved,
+    Unrecognised,
+}
+
+impl Verdict {
+    pub fn from_html(body: &str) -> Verdict {
+        if body.contains("That's the right answer") {
+            Verdict::Correct
+        } else if body.contains("That's not the right answer") {
+            if body.contains("your answer is too high") {
+                Verdict::TooHigh
+            } else if body.contains("your answer is too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Incorrect
+            }
+        } else if body.contains("You gave an answer too recently") {
+            // "... You have 1m 30s left to wait."
+            let wait = body
+                .split_once("You have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .map(|(wait, _)| wait.to_string());
+            Verdict::RateLimited { wait }
+        } else if body.contains("You don't seem to be solving the right level") {
+            Verdict::AlreadySolved
+        } else {
+            Verdict::Unrecognised
+        }
+    }
+
+    pub fn is_wrong(&self) -> bool {
+        matches!(
+            self,
+            Verdict::Incorrect | Verdict::TooHigh | Verdict::TooLow
+        )
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Incorrect => write!(f, "wrong"),
+            Verdict::TooHigh => write!(f, "wrong (too high)"),
+            Verdict::TooLow => write!(f, "wrong (too low)"),
+            Verdict::RateLimited { wait: Some(wait) } => {
+                write!(f, "rate limited, {} left to wait", wait)
+            }
+            Verdict::RateLimited { wait: None } => write!(f, "rate limited"),
+            Verdict::AlreadySolved => write!(f, "not accepted, the part is already solved"),
+            Verdict::Unrecognised => write!(f, "unrecognised response"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Attempt {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+}
+
+/// Exclusive bounds on a numeric answer learned from too-high/too-low
+/// verdicts.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Bounds {
+    pub low: Option<i64>,
+    pub high: Option<i64>,
+}
+
+impl Bounds {
+    pub fn from_attempts<'a>(attempts: impl IntoIterator<Item = &'a Attempt>) -> Bounds {
+        let mut bounds = Bounds::default();
+        for attempt in attempts {
+            let Ok(value) = attempt.answer.parse::<i64>() else {
+                continue;
+            };
+            match attempt.verdict {
+                Verdict::TooLow => bounds.low = Some(bounds.low.map_or(value, |l| l.max(value))),
+                Verdict::TooHigh => bounds.high = Some(bounds.high.map_or(value, |h| h.min(value))),
+                _ => {}
+            }
+        }
+        bounds
+    }
+
+    pub fn is_known(&self) -> bool {
+        self.low.is_some() || self.high.is_some()
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.low.is_none_or(|low| value > low) && self.high.is_none_or(|high| value < high)
+    }
+}
+
+impl Display for Bounds {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.low, self.high) {
+            (Some(low), Some(high)) => write!(f, "the answer is between {} and {}", low, high),
+            (Some(low), None) => write!(f, "the answer is greater than {}", low),
+            (None, Some(high)) => write!(f, "the answer is less than {}", high),
+            (None, None) => write!(f, "nothing is known about the answer yet"),
+        }
+    }
+}
+
+/// Why an answer was not sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadySolved { answer: String },
+    KnownWrong { answer: String, verdict: Verdict },
+    OutOfBounds { answer: String, bounds: Bounds },
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::AlreadySolved { answer } => {
+                write!(f, "Already solved, the accepted answer was {}", answer)
+            }
+            Refusal::KnownWrong { answer, verdict } => {
+                write!(f, "{} was already submitted and was {}", answer, verdict)
+            }
+            Refusal::OutOfBounds { answer, bounds } => {
+                write!(f, "{} can't be right, {}", answer, bounds)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Refusal {}
+
+/// Checks a candidate answer against the earlier attempts for the same part.
+pub fn check(history: &[Attempt], answer: &Answer) -> Result<(), Refusal> {
+    let text = answer.to_string();
+
+    if let Some(correct) = history.iter().find(|a| a.verdict == Verdict::Correct) {
+        return Err(Refusal::AlreadySolved {
+            answer: correct.answer.clone(),
+        });
+    }
+
+    if let Some(wrong) = history
+        .iter()
+        .find(|a| a.answer == text && a.verdict.is_wrong())
+    {
+        return Err(Refusal::KnownWrong {
+            answer: text,
+            verdict: wrong.verdict.clone(),
+        });
+    }
+
+    let bounds = Bounds::from_attempts(history);
+    if let Some(value) = answer.as_number() {
+        if !bounds.contains(value) {
+            return Err(Refusal::OutOfBounds {
+                answer: text,
+                bounds,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Every submission ever made, one JSON object per line.
+pub struct SubmissionLog {
+    path: PathBuf,
+}
+
+impl SubmissionLog {
+    pub fn new(path: PathBuf) -> Self {
+        SubmissionLog { path }
+    }
+
+    pub fn load(&self) -> Result<Vec<Attempt>> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+        let text = fs::read_to_string(&self.path)
+            .with_context(|| format!("Failed to read {}", self.path.display()))?;
+        text.lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(idx, line)| {
+                serde_json::from_str(line).with_context(|| {
+                    format!("{}:{}: invalid attempt", self.path.display(), idx + 1)
+                })
+            })
+            .collect()
+    }
+
+    pub fn history(&self, year: u16, day: u8, part: Part) -> Result<Vec<Attempt>> {
+        Ok(self
+            .load()?
+            .into_iter()
+            .filter(|a| a.year == year && a.day == day && a.part == part.number())
+            .collect())
+    }
+
+    pub fn append(&self, attempt: &Attempt) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .with_context(|| format!("Failed to open {}", self.path.display()))?;
+        writeln!(file, "{}", serde_json::to_string(attempt)?)?;
+        Ok(())
+    }
+}
+
+pub struct Submitter<B: HttpBackend> {
+    backend: B,
+    base_url: String,
+    session: String,
+    log: SubmissionLog,
+}
+
+impl<B: HttpBackend> Submitter<B> {
+    pub fn new(backend: B, base_url: &str, session: String, log: SubmissionLog) -> Self {
+        Submitter {
+            backend,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            log,
+        }
+    }
+
+    /// Submits an answer unless earlier attempts already rule it out, and
+    /// records the verdict.
+    pub fn submit(&self, year: u16, day: u8, part: Part, answer: &Answer) -> Result<Verdict> {
+        let history = self.log.history(year, day, part)?;
+        check(&history, answer)?;
+
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let level = part.number().to_string();
+        let text = answer.to_string();
+        let response = self.backend.post_form(
+            &url,
+            &self.session,
+            &[("level", level.as_str()), ("answer", text.as_str())],
+        )?;
+        if response.status != 200 {
+            return Err(anyhow!(
+                "POST {} returned {}: {}",
+                url,
+                response.status,
+                response.body.trim()
+            ));
+        }
+
+        let verdict = Verdict::from_html(&response.body);
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        self.log.append(&Attempt {
+            year,
+            day,
+            part: part.number(),
+            answer: text,
+            verdict: verdict.clone(),
+            timestamp,
+        })?;
+        Ok(verdict)
+    }
+
+    /// What the recorded attempts say about the answer so far.
+    pub fn bounds(&self, year: u16, day: u8, part: Part) -> Result<Bounds> {
+        Ok(Bounds::from_attempts(&self.log.history(year, day, part)?))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::http::UreqBackend;
+    use crate::stub_server::StubServer;
+
+    fn scratch_log(name: &str) -> SubmissionLog {
+        let dir = std::env::temp_dir().join(format!("aoc-submit-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        SubmissionLog::new(dir.join("submissions.jsonl"))
+    }
+
+    fn page(message: &str) -> String {
+        format!(
+            "<html><body><main><article><p>{}</p></article></main></body></html>",
+            message
+        )
+    }
+
+    #[test]
+    fn test_verdict_from_html() {
+        let cases = [
+            ("That's the right answer!  You are one gold star closer.", Verdict::Correct),
+            ("That's not the right answer.  If you're stuck, ...", Verdict::Incorrect),
+            ("That's not the right answer; your answer is too high.", Verdict::TooHigh),
+            ("That's not the right answer; your answer is too low.", Verdict::TooLow),
+            (
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 30s left to wait.",
+                Verdict::RateLimited {
+                    wait: Some("1m 30s".to_string()),
+                },
+            ),
+            (
+                "You don't seem to be solving the right level.  Did you already complete it?",
+                Verdict::AlreadySolved,
+            ),
+            ("Something else entirely", Verdict::Unrecognised),
+        ];
+        for (message, expected) in cases {
+            assert_eq!(Verdict::from_html(&page(message)), expected);
+        }
+    }
+
+    #[test]
+    fn test_bounds_narrow_with_each_verdict() {
+        let attempt = |answer: &str, verdict| Attempt {
+            year: 2024,
+            day: 1,
+            part: 1,
+            answer: answer.to_string(),
+            verdict,
+            timestamp: 0,
+        };
+        let history = vec![
+            attempt("10", Verdict::TooLow),
+            attempt("90", Verdict::TooHigh),
+            attempt("20", Verdict::TooLow),
+            attempt("70", Verdict::TooHigh),
+            attempt("50", Verdict::Incorrect),
+        ];
+
+        let bounds = Bounds::from_attempts(&history);
+        assert_eq!(bounds.to_string(), "the answer is between 20 and 70");
+
+        assert_eq!(check(&history, &Answer::from(30usize)), Ok(()));
+        assert!(matches!(
+            check(&history, &Answer::from(50usize)),
+            Err(Refusal::KnownWrong { .. })
+        ));
+        assert!(matches!(
+            check(&history, &Answer::from(15usize)),
+            Err(Refusal::OutOfBounds { .. })
+        ));
+        assert!(matches!(
+            check(&history, &Answer::from(75usize)),
+            Err(Refusal::OutOfBounds { .. })
+        ));
+    }
+
+    #[test]
+    fn test_submit_against_stub_server() {
+        let server = StubServer::start(|request| {
+            let body = match request.body.as_str() {
+                "level=1&answer=100" => {
+                    page("That's not the right answer; your answer is too high.")
+                }
+                "level=1&answer=42" => page("That's the right answer!"),
+                _ => page("That's not the right answer."),
+            };
+            if request.method == "POST" && request.path == "/2024/day/1/answer" {
+                (200, body)
+            } else {
+                (404, "Not Found".to_string())
+            }
+        });
+        let submitter = Submitter::new(
+            UreqBackend::new(),
+            &server.url(),
+            "tok".to_string(),
+            scratch_log("stub"),
+        );
+        let submit = |n: usize| submitter.submit(2024, 1, Part::One, &Answer::from(n));
+
+        assert_eq!(submit(100).unwrap(), Verdict::TooHigh);
+        assert_eq!(
+            submitter.bounds(2024, 1, Part::One).unwrap().to_string(),
+            "the answer is less than 100"
+        );
+
+        // neither a repeat nor anything above a too-high answer is sent
+        let err = submit(100).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<Refusal>(),
+            Some(Refusal::KnownWrong { .. })
+        ));
+        let err = submit(150).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<Refusal>(),
+            Some(Refusal::OutOfBounds { .. })
+        ));
+        assert_eq!(server.request_count(), 1);
+
+        assert_eq!(submit(42).unwrap(), Verdict::Correct);
+        let err = submit(43).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Already solved, the accepted answer was 42"
+        );
+        assert_eq!(server.request_count(), 2);
+
+        let other_part = submitter.submit(2024, 1, Part::Two, &Answer::from(100usize));
+        assert_eq!(other_part.unwrap(), Verdict::Incorrect);
+        assert_eq!(submitter.log.load().unwrap().len(), 3);
+    }
+}
@@ -0,0 +1,83 @@
+use std::{convert::Infallible, fmt::Display, str::FromStr};
+
+/// The result of solving one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl Answer {
+    pub fn as_number(&self) -> Option<i64> {
+        match self {
+            Answer::Number(n) => Some(*n),
+            Answer::Text(_) => None,
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        match s.parse::<i64>() {
+            Ok(n) => Ok(Answer::Number(n)),
+            Err(_) => Ok(Answer::Text(s.to_string())),
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        Answer::Number(value as i64)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Number(value)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Number(i64::try_from(value).expect("answer does not fit in an i64"))
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_round_trip() {
+        for text in ["0", "-12", "661823605105500", "abc,def"] {
+            let answer: Answer = text.parse().unwrap();
+            assert_eq!(answer.to_string(), text);
+        }
+        assert_eq!("42".parse::<Answer>().unwrap(), Answer::from(42usize));
+        assert_eq!("4x".parse::<Answer>().unwrap(), Answer::from("4x"));
+    }
+}
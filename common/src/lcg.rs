@@ -0,0 +1,42 @@
+/// A small linear congruential generator, so tests and benchmarks can
+/// generate the same inputs on every run without a random number crate. Not
+/// for anything that needs good randomness.
+pub struct Lcg(u64);
+
+impl Lcg {
+    pub fn new(seed: u64) -> Lcg {
+        Lcg(seed)
+    }
+
+    /// The next 31 bits, taken from the top of the state where they are the
+    /// least predictable.
+    pub fn next_u32(&mut self) -> u32 {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (self.0 >> 33) as u32
+    }
+
+    /// The next value in `0..n`.
+    pub fn below(&mut self, n: u32) -> u32 {
+        self.next_u32() % n
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_reproducible() {
+        let mut a = Lcg::new(7);
+        let mut b = Lcg::new(7);
+        for _ in 0..100 {
+            let value = a.below(10);
+            assert!(value < 10);
+            assert_eq!(value, b.below(10));
+        }
+        assert_ne!(Lcg::new(1).next_u32(), Lcg::new(2).next_u32());
+    }
+}
@@ -0,0 +1,9 @@
+mod answer;
+mod lcg;
+mod parse_error;
+mod solution;
+
+pub use answer::Answer;
+pub use lcg::Lcg;
+pub use parse_error::{parse_number, ParseError};
+pub use solution::{Part, Solution};
@@ -0,0 +1,163 @@
+use std::{fmt::Display, str::FromStr};
+
+/// Malformed puzzle input: what was wrong, where it was, and the line it was
+/// on, so the error can point at the offending text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    message: String,
+    line: usize,
+    column: usize,
+    text: String,
+    source_line: String,
+}
+
+impl ParseError {
+    /// An error about `text`, which must be a slice of `source`. The line and
+    /// column are worked out from where `text` sits in `source`.
+    pub fn at(source: &str, text: &str, message: impl Into<String>) -> ParseError {
+        let start = (text.as_ptr() as usize)
+            .checked_sub(source.as_ptr() as usize)
+            .filter(|start| start + text.len() <= source.len())
+            .expect("text is not part of the source");
+
+        let line_start = source[..start].rfind('\n').map_or(0, |idx| idx + 1);
+        let line_end = source[start..]
+            .find('\n')
+            .map_or(source.len(), |idx| start + idx);
+
+        ParseError {
+            message: message.into(),
+            line: source[..start].matches('\n').count() + 1,
+            column: source[line_start..start].chars().count() + 1,
+            text: text.to_string(),
+            source_line: source[line_start..line_end].to_string(),
+        }
+    }
+
+    /// Moves the error down by `lines`, for when `source` was a single line
+    /// or section cut out of a larger input.
+    pub fn offset_lines(mut self, lines: usize) -> ParseError {
+        self.line += lines;
+        self
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// 1-based line number.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// 1-based column, counted in characters.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+/// Control characters such as a stray `\r` are shown escaped, otherwise they
+/// would be invisible in the snippet.
+fn escape(s: &str) -> String {
+    s.chars()
+        .map(|c| {
+            if c.is_control() {
+                c.escape_debug().to_string()
+            } else {
+                c.to_string()
+            }
+        })
+        .collect()
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        if !self.text.is_empty() {
+            write!(f, " {:?}", self.text)?;
+        }
+        writeln!(f)?;
+
+        let chars: Vec<char> = self.source_line.chars().collect();
+        let before: String = chars[..self.column - 1].iter().collect();
+        // only underline the part of the text that is on this line
+        let underlined = self.text.split('\n').next().unwrap_or_default();
+
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line, escape(&self.source_line))?;
+        write!(
+            f,
+            "{} | {}{}",
+            gutter,
+            " ".repeat(escape(&before).chars().count()),
+            "^".repeat(escape(underlined).chars().count().max(1))
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses `text`, a slice of `source`, as a number.
+pub fn parse_number<T: FromStr>(source: &str, text: &str) -> Result<T, ParseError> {
+    text.parse()
+        .map_err(|_| ParseError::at(source, text, "invalid number"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_location() {
+        let input = "1 2 3\n4 x 6\n";
+        let err = ParseError::at(input, &input[8..9], "invalid number");
+        assert_eq!(err.line(), 2);
+        assert_eq!(err.column(), 3);
+        assert_eq!(err.text(), "x");
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: invalid number \"x\"\n  |\n2 | 4 x 6\n  |   ^"
+        );
+    }
+
+    #[test]
+    fn test_offset_lines() {
+        let line = "10|2a";
+        let err = parse_number::<usize>(line, &line[3..])
+            .unwrap_err()
+            .offset_lines(11);
+        assert_eq!(
+            err.to_string(),
+            "line 12, column 4: invalid number \"2a\"\n   |\n12 | 10|2a\n   |    ^^"
+        );
+    }
+
+    #[test]
+    fn test_control_characters_are_visible() {
+        let input = "12\r34";
+        let err = ParseError::at(input, &input[2..3], "invalid character");
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 3: invalid character \"\\r\"\n  |\n1 | 12\\r34\n  |   ^^"
+        );
+    }
+
+    #[test]
+    fn test_empty_text_points_at_position() {
+        let input = "1|";
+        let err = ParseError::at(input, &input[2..], "expected a page number");
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 3: expected a page number\n  |\n1 | 1|\n  |   ^"
+        );
+    }
+}
@@ -0,0 +1,55 @@
+use crate::Answer;
+use anyhow::{anyhow, Result};
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = anyhow::Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(anyhow!("Invalid part {}", value)),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// A day's puzzle: the input is parsed once and both parts are answered
+/// from the parsed form.
+pub trait Solution {
+    type Parsed;
+
+    fn parse(input: &str) -> Result<Self::Parsed>;
+    fn part1(parsed: &Self::Parsed) -> Result<Answer>;
+    fn part2(parsed: &Self::Parsed) -> Result<Answer>;
+
+    fn solve(parsed: &Self::Parsed, part: Part) -> Result<Answer> {
+        match part {
+            Part::One => Self::part1(parsed),
+            Part::Two => Self::part2(parsed),
+        }
+    }
+}
@@ -0,0 +1,71 @@
+//! Compares the merge-based similarity score with the original quadratic
+//! scan on a generated million-line input. By default the quadratic version
+//! only runs on the first 20,000 lines, and its time for the full input is
+//! extrapolated from that, as a full run takes several minutes.
+//!
+//! Run with `cargo bench -p day1`, or with
+//! `cargo bench -p day1 --bench similarity -- --full-naive`
+//! to also time the quadratic version on every line.
+
+use common::{Lcg, Solution};
+use day1::{calculate_similarity, calculate_similarity_naive, Day1};
+use std::env;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const LINES: usize = 1_000_000;
+const NAIVE_PREFIX: usize = 20_000;
+
+/// Location IDs in the same five digit range as the real input, from a
+/// linear congruential generator so every run sees the same lists.
+fn generate(lines: usize) -> String {
+    let mut rng = Lcg::new(2024);
+    let mut next = || 10_000 + rng.below(90_000);
+
+    let mut input = String::with_capacity(lines * 14);
+    for _ in 0..lines {
+        input.push_str(&format!("{}   {}\n", next(), next()));
+    }
+    input
+}
+
+fn time<T>(f: impl Fn() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = black_box(f());
+    (result, start.elapsed())
+}
+
+fn main() {
+    let input = generate(LINES);
+    let (left, right) = Day1::parse(&input).expect("generated input parses");
+
+    let (merged, merge_time) = time(|| calculate_similarity(black_box(&left), black_box(&right)));
+    println!(
+        "merge, {} lines: {:?} (score {})",
+        LINES, merge_time, merged
+    );
+
+    let (left_prefix, right_prefix) = (&left[..NAIVE_PREFIX], &right[..NAIVE_PREFIX]);
+    let (naive, naive_time) =
+        time(|| calculate_similarity_naive(black_box(left_prefix), black_box(right_prefix)));
+    let (prefix, prefix_time) =
+        time(|| calculate_similarity(black_box(left_prefix), black_box(right_prefix)));
+    assert_eq!(naive, prefix, "both methods agree on the prefix");
+
+    let scale = (LINES / NAIVE_PREFIX).pow(2) as u32;
+    println!(
+        "naive, {} lines: {:?} (merge {:?}), extrapolated to about {:?} for {} lines",
+        NAIVE_PREFIX,
+        naive_time,
+        prefix_time,
+        naive_time * scale,
+        LINES
+    );
+
+    if env::args().any(|arg| arg == "--full-naive") {
+        let (naive, naive_time) =
+            time(|| calculate_similarity_naive(black_box(&left), black_box(&right)));
+        assert_eq!(naive, merged, "both methods agree on the full input");
+        println!("naive, {} lines: {:?}", LINES, naive_time);
+    }
+}
@@ -0,0 +1,168 @@
+use anyhow::Result;
+use common::{parse_number, Answer, ParseError, Solution};
+
+mod stats;
+mod stream;
+
+pub use stats::{Bucket, Contribution, ListStats, Stats};
+pub use stream::{stream_totals, StreamOptions, StreamTotals};
+
+/// Similarity score of two sorted lists: each left value times how often it
+/// appears on the right. Equal values are found as runs in a single merge
+/// pass over both lists.
+pub fn calculate_similarity(left: &[i64], right: &[i64]) -> i64 {
+    let mut total_similarity = 0;
+    let (mut l, mut r) = (0, 0);
+
+    while l < left.len() {
+        let v = left[l];
+        let left_run = left[l..].iter().take_while(|&&x| x == v).count();
+        while r < right.len() && right[r] < v {
+            r += 1;
+        }
+        let right_run = right[r..].iter().take_while(|&&x| x == v).count();
+
+        total_similarity += v * (left_run * right_run) as i64;
+        l += left_run;
+        r += right_run;
+    }
+
+    total_similarity
+}
+
+/// The original scan of `right` for every left value, kept as a reference
+/// for tests and the similarity benchmark. Works on unsorted lists.
+pub fn calculate_similarity_naive(left: &[i64], right: &[i64]) -> i64 {
+    let mut total_similarity = 0;
+    for &v in left {
+        let count = right.iter().filter(|x| v == **x).count();
+        total_similarity += v * count as i64;
+    }
+
+    total_similarity
+}
+
+fn calculate_distance(left: &[i64], right: &[i64]) -> i64 {
+    let mut total_distance = 0;
+
+    for (&l, &r) in left.iter().zip(right) {
+        total_distance += l.abs_diff(r) as i64;
+    }
+
+    total_distance
+}
+
+/// Splits a row into its two location IDs. Any run of whitespace separates
+/// the columns.
+fn parse_row(line: &str) -> Result<(i64, i64), ParseError> {
+    let mut fields = line.split_whitespace();
+    match (fields.next(), fields.next(), fields.next()) {
+        (Some(l_val), Some(r_val), None) => {
+            Ok((parse_number(line, l_val)?, parse_number(line, r_val)?))
+        }
+        (Some(_), Some(_), Some(extra)) => Err(ParseError::at(
+            line,
+            extra,
+            "expected two numbers, found a third",
+        )),
+        _ => Err(ParseError::at(
+            line,
+            &line[line.trim_end().len()..],
+            "expected two numbers",
+        )),
+    }
+}
+
+/// Reads both columns and sorts them. Blank lines are skipped, so a trailing
+/// newline or two is harmless.
+fn process_data(content: &str) -> Result<(Vec<i64>, Vec<i64>), ParseError> {
+    let mut left = Vec::new();
+    let mut right = Vec::new();
+
+    for (idx, l) in content.lines().enumerate() {
+        if l.trim().is_empty() {
+            continue;
+        }
+        let (l_val, r_val) = parse_row(l).map_err(|e| e.offset_lines(idx))?;
+        left.push(l_val);
+        right.push(r_val);
+    }
+
+    left.sort();
+    right.sort();
+
+    Ok((left, right))
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Parsed = (Vec<i64>, Vec<i64>);
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(process_data(input)?)
+    }
+
+    fn part1((left, right): &Self::Parsed) -> Result<Answer> {
+        Ok(calculate_distance(left, right).into())
+    }
+
+    fn part2((left, right): &Self::Parsed) -> Result<Answer> {
+        Ok(calculate_similarity(left, right).into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_similarity_matches_naive() {
+        let (left, right) = process_data("3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n").unwrap();
+        assert_eq!(calculate_similarity(&left, &right), 31);
+
+        let cases: [(&[i64], &[i64]); 4] = [
+            (&[], &[]),
+            (&[1, 1, 2], &[]),
+            (&[1, 1, 2, 5, 5], &[1, 2, 2, 2, 5, 6]),
+            (&[7, 8, 9], &[1, 2, 3]),
+        ];
+        for (left, right) in cases {
+            assert_eq!(
+                calculate_similarity(left, right),
+                calculate_similarity_naive(left, right)
+            );
+        }
+    }
+
+    #[test]
+    fn test_any_whitespace() {
+        let expected = process_data("3   4\n4   3\n2   5\n").unwrap();
+        for input in [
+            "3\t4\n4\t3\n2\t5\n",
+            "3 4\n4 3\n2 5",
+            "3   4\r\n4   3\r\n2   5\r\n",
+            "  3 \t 4  \n4 3\n\n2 5\n\n\n",
+        ] {
+            assert_eq!(process_data(input).unwrap(), expected, "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn test_large_ids() {
+        let (left, right) = process_data("3000000000   3000000001\n").unwrap();
+        assert_eq!(calculate_distance(&left, &right), 1);
+    }
+
+    #[test]
+    fn test_row_errors() {
+        let err = process_data("3   4\n4   3   7\n").unwrap_err();
+        assert_eq!((err.line(), err.column(), err.text()), (2, 9, "7"));
+
+        let err = process_data("3   4\n\n4\n").unwrap_err();
+        assert_eq!((err.line(), err.column(), err.text()), (3, 2, ""));
+
+        let err = process_data("3   4\n4   x\n").unwrap_err();
+        assert_eq!((err.line(), err.column(), err.text()), (2, 5, "x"));
+    }
+}
@@ -0,0 +1,267 @@
+use std::fmt::Display;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListStats {
+    pub min: i64,
+    pub max: i64,
+    /// The middle value, or the mean of the two middle values for a list of
+    /// even length.
+    pub median: f64,
+}
+
+impl ListStats {
+    /// `None` for an empty list. `sorted` must be in ascending order.
+    fn new(sorted: &[i64]) -> Option<ListStats> {
+        let mid = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (*sorted.get(mid.checked_sub(1)?)? as f64 + sorted[mid] as f64) / 2.0
+        } else {
+            sorted[mid] as f64
+        };
+        Some(ListStats {
+            min: *sorted.first()?,
+            max: *sorted.last()?,
+            median,
+        })
+    }
+}
+
+/// Pairs whose distance is in `low..=high`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bucket {
+    pub low: u64,
+    pub high: u64,
+    pub count: usize,
+}
+
+/// A value found in both lists and what it adds to the similarity score.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Contribution {
+    pub value: i64,
+    pub left_count: usize,
+    pub right_count: usize,
+    pub contribution: i64,
+}
+
+/// Everything `aoc day1 --stats` reports about the two lists.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub left: Option<ListStats>,
+    pub right: Option<ListStats>,
+    /// Distances between the pairs the total distance is made of, in
+    /// buckets that double in width: 0, 1, 2-3, 4-7 and so on. Empty buckets
+    /// past the largest distance are left out.
+    pub distances: Vec<Bucket>,
+    /// The values adding the most to the similarity score, largest first.
+    pub top: Vec<Contribution>,
+    /// Distinct values that only appear in one of the lists.
+    pub only_left: Vec<i64>,
+    pub only_right: Vec<i64>,
+}
+
+impl Stats {
+    /// Statistics of two sorted lists, as returned by `Day1::parse`, keeping
+    /// the `top_n` largest similarity contributions.
+    pub fn new(left: &[i64], right: &[i64], top_n: usize) -> Stats {
+        let mut distances: Vec<Bucket> = Vec::new();
+        for (&l, &r) in left.iter().zip(right) {
+            let distance = l.abs_diff(r);
+            let bucket = (u64::BITS - distance.leading_zeros()) as usize;
+            while distances.len() <= bucket {
+                let (low, high) = match distances.len() {
+                    0 => (0, 0),
+                    n => (1 << (n - 1), u64::MAX >> (64 - n)),
+                };
+                distances.push(Bucket {
+                    low,
+                    high,
+                    count: 0,
+                });
+            }
+            distances[bucket].count += 1;
+        }
+
+        let mut top = Vec::new();
+        let mut only_left = Vec::new();
+        let mut only_right = Vec::new();
+        let (mut l_runs, mut r_runs) = (runs(left).peekable(), runs(right).peekable());
+        loop {
+            match (l_runs.peek().copied(), r_runs.peek().copied()) {
+                (Some((l, _)), Some((r, _))) if l < r => {
+                    only_left.push(l);
+                    l_runs.next();
+                }
+                (Some((l, _)), Some((r, _))) if r < l => {
+                    only_right.push(r);
+                    r_runs.next();
+                }
+                (Some((value, left_count)), Some((_, right_count))) => {
+                    top.push(Contribution {
+                        value,
+                        left_count,
+                        right_count,
+                        contribution: value * (left_count * right_count) as i64,
+                    });
+                    l_runs.next();
+                    r_runs.next();
+                }
+                (Some((l, _)), None) => {
+                    only_left.push(l);
+                    l_runs.next();
+                }
+                (None, Some((r, _))) => {
+                    only_right.push(r);
+                    r_runs.next();
+                }
+                (None, None) => break,
+            }
+        }
+
+        top.sort_by_key(|c| std::cmp::Reverse(c.contribution));
+        top.truncate(top_n);
+
+        Stats {
+            left: ListStats::new(left),
+            right: ListStats::new(right),
+            distances,
+            top,
+            only_left,
+            only_right,
+        }
+    }
+}
+
+/// Each distinct value of a sorted list with how often it appears.
+fn runs(sorted: &[i64]) -> impl Iterator<Item = (i64, usize)> + '_ {
+    sorted
+        .chunk_by(|a, b| a == b)
+        .map(|run| (run[0], run.len()))
+}
+
+/// At most this many values are listed for each side in the report, the
+/// counts are always given in full.
+const LISTED_VALUES: usize = 10;
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (name, list) in [("Left", &self.left), ("Right", &self.right)] {
+            match list {
+                Some(list) => writeln!(
+                    f,
+                    "{} list: min {}, max {}, median {}",
+                    name, list.min, list.max, list.median
+                )?,
+                None => writeln!(f, "{} list: empty", name)?,
+            }
+        }
+
+        writeln!(f, "\nPair distances:")?;
+        let widest = self.distances.iter().map(|b| b.count).max().unwrap_or(0);
+        for bucket in &self.distances {
+            let range = match bucket.low == bucket.high {
+                true => bucket.low.to_string(),
+                false => format!("{}-{}", bucket.low, bucket.high),
+            };
+            // bars are scaled so the largest bucket is 40 characters wide
+            let bar = "#".repeat((bucket.count * 40).div_ceil(widest.max(1)));
+            let row = format!("  {:>13} {:>8} {}", range, bucket.count, bar);
+            writeln!(f, "{}", row.trim_end())?;
+        }
+
+        writeln!(f, "\nLargest similarity contributions:")?;
+        if self.top.is_empty() {
+            writeln!(f, "  none, no value is in both lists")?;
+        }
+        for c in &self.top {
+            writeln!(
+                f,
+                "  {} x {} left x {} right = {}",
+                c.value, c.left_count, c.right_count, c.contribution
+            )?;
+        }
+
+        writeln!(f)?;
+        for (name, values) in [("left", &self.only_left), ("right", &self.only_right)] {
+            let listed: Vec<String> = values
+                .iter()
+                .take(LISTED_VALUES)
+                .map(|v| v.to_string())
+                .collect();
+            let more = match values.len() > LISTED_VALUES {
+                true => ", ...",
+                false => "",
+            };
+            writeln!(
+                f,
+                "Only in the {} list: {} values [{}{}]",
+                name,
+                values.len(),
+                listed.join(", "),
+                more
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Day1;
+    use common::Solution;
+
+    #[test]
+    fn test_example_stats() {
+        let (left, right) = Day1::parse("3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n").unwrap();
+        let stats = Stats::new(&left, &right, 2);
+
+        assert_eq!(
+            stats.left,
+            Some(ListStats {
+                min: 1,
+                max: 4,
+                median: 3.0
+            })
+        );
+        assert_eq!(stats.right.as_ref().map(|r| r.median), Some(3.5));
+
+        // sorted pairs are 1-3, 2-3, 3-3, 3-4, 3-5 and 4-9
+        let counts: Vec<(u64, u64, usize)> = stats
+            .distances
+            .iter()
+            .map(|b| (b.low, b.high, b.count))
+            .collect();
+        assert_eq!(counts, [(0, 0, 1), (1, 1, 2), (2, 3, 2), (4, 7, 1)]);
+
+        assert_eq!(
+            stats.top,
+            [
+                Contribution {
+                    value: 3,
+                    left_count: 3,
+                    right_count: 3,
+                    contribution: 27
+                },
+                Contribution {
+                    value: 4,
+                    left_count: 1,
+                    right_count: 1,
+                    contribution: 4
+                }
+            ]
+        );
+        assert_eq!(stats.only_left, [1, 2]);
+        assert_eq!(stats.only_right, [5, 9]);
+    }
+
+    #[test]
+    fn test_empty_lists() {
+        let stats = Stats::new(&[], &[], 5);
+        assert_eq!((stats.left, stats.right), (None, None));
+        assert!(stats.distances.is_empty() && stats.top.is_empty());
+
+        let stats = Stats::new(&[i64::MIN], &[i64::MAX], 5);
+        let widest = stats.distances.last().unwrap();
+        assert_eq!((widest.low, widest.high), (1 << 63, u64::MAX));
+    }
+}
@@ -0,0 +1,300 @@
+use crate::{calculate_distance, calculate_similarity, parse_row};
+use anyhow::{Context, Result};
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    env,
+    fs::{self, File},
+    io::{self, BufRead, BufReader, BufWriter, Read, Write},
+    path::PathBuf,
+    process,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// How `stream_totals` bounds its memory use.
+#[derive(Debug, Clone)]
+pub struct StreamOptions {
+    /// Values held per list before they are sorted and spilled to disk.
+    pub run_len: usize,
+    /// Where the spilled runs go, in a directory of their own that is
+    /// removed once the totals are known.
+    pub temp_dir: PathBuf,
+}
+
+impl Default for StreamOptions {
+    /// Runs of a million values, 8MB per list, in the system temp directory.
+    fn default() -> Self {
+        StreamOptions {
+            run_len: 1 << 20,
+            temp_dir: env::temp_dir(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StreamTotals {
+    pub rows: usize,
+    pub distance: i64,
+    pub similarity: i64,
+}
+
+/// Total distance and similarity of the two lists in `reader`, without
+/// holding either list in memory.
+///
+/// Each list is read in runs of `run_len` values that are sorted and written
+/// to a temporary file. The runs are then merged back into one sorted stream
+/// per list: zipping the two streams gives the distance, and a second merge
+/// counts equal values on each side for the similarity. Input that fits in a
+/// single run never touches the disk.
+pub fn stream_totals(mut reader: impl BufRead, options: &StreamOptions) -> Result<StreamTotals> {
+    let run_len = options.run_len.max(1);
+    let mut left = Vec::with_capacity(run_len);
+    let mut right = Vec::with_capacity(run_len);
+    let mut spill: Option<Spill> = None;
+    let mut rows = 0;
+
+    let mut line = String::new();
+    for idx in 0.. {
+        line.clear();
+        if reader
+            .read_line(&mut line)
+            .context("Failed to read input")?
+            == 0
+        {
+            break;
+        }
+        if line.trim().is_empty() {
+            continue;
+        }
+        let (l_val, r_val) =
+            parse_row(line.trim_end_matches(['\n', '\r'])).map_err(|e| e.offset_lines(idx))?;
+        left.push(l_val);
+        right.push(r_val);
+        rows += 1;
+
+        if left.len() == run_len {
+            let spill = match &mut spill {
+                Some(spill) => spill,
+                None => spill.insert(Spill::new(options)?),
+            };
+            spill.write_runs(&mut left, &mut right)?;
+        }
+    }
+
+    let Some(mut spill) = spill else {
+        left.sort();
+        right.sort();
+        return Ok(StreamTotals {
+            rows,
+            distance: calculate_distance(&left, &right),
+            similarity: calculate_similarity(&left, &right),
+        });
+    };
+    if !left.is_empty() {
+        spill.write_runs(&mut left, &mut right)?;
+    }
+    // the merges below have their own buffers, these are no longer needed
+    drop((left, right));
+
+    let mut distance = 0;
+    let (mut l_sorted, mut r_sorted) = spill.merged()?;
+    while let (Some(l), Some(r)) = (l_sorted.next()?, r_sorted.next()?) {
+        distance += l.abs_diff(r) as i64;
+    }
+
+    let mut similarity = 0;
+    let (mut l_sorted, mut r_sorted) = spill.merged()?;
+    while let Some(v) = l_sorted.peek() {
+        let left_run = l_sorted.skip_equal(v)?;
+        while r_sorted.peek().is_some_and(|r| r < v) {
+            r_sorted.next()?;
+        }
+        let right_run = r_sorted.skip_equal(v)?;
+        similarity += v * (left_run * right_run) as i64;
+    }
+
+    Ok(StreamTotals {
+        rows,
+        distance,
+        similarity,
+    })
+}
+
+/// Sorted runs of both lists on disk, as raw little-endian `i64`s. The
+/// directory holding them is deleted when this is dropped.
+struct Spill {
+    dir: PathBuf,
+    left: Vec<PathBuf>,
+    right: Vec<PathBuf>,
+}
+
+impl Spill {
+    fn new(options: &StreamOptions) -> Result<Spill> {
+        // several streams can run at once in one process, as the tests do
+        static NEXT: AtomicUsize = AtomicUsize::new(0);
+        let dir = options.temp_dir.join(format!(
+            "day1-stream-{}-{}",
+            process::id(),
+            NEXT.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::create_dir(&dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+        Ok(Spill {
+            dir,
+            left: Vec::new(),
+            right: Vec::new(),
+        })
+    }
+
+    /// Sorts and writes out both buffers, leaving them empty.
+    fn write_runs(&mut self, left: &mut Vec<i64>, right: &mut Vec<i64>) -> Result<()> {
+        let run = self.left.len();
+        for (side, values, runs) in [
+            ("left", left, &mut self.left),
+            ("right", right, &mut self.right),
+        ] {
+            let path = self.dir.join(format!("{}-{}.bin", side, run));
+            values.sort_unstable();
+            let write = || -> io::Result<()> {
+                let mut out = BufWriter::new(File::create(&path)?);
+                for v in values.iter() {
+                    out.write_all(&v.to_le_bytes())?;
+                }
+                out.flush()
+            };
+            write().with_context(|| format!("Failed to write {}", path.display()))?;
+            values.clear();
+            runs.push(path);
+        }
+        Ok(())
+    }
+
+    fn merged(&self) -> Result<(Merge, Merge)> {
+        Ok((Merge::new(&self.left)?, Merge::new(&self.right)?))
+    }
+}
+
+impl Drop for Spill {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.dir);
+    }
+}
+
+/// A k-way merge of sorted run files into one sorted stream.
+struct Merge {
+    runs: Vec<BufReader<File>>,
+    /// The next value of every run that has one left, smallest on top.
+    heads: BinaryHeap<Reverse<(i64, usize)>>,
+}
+
+impl Merge {
+    fn new(paths: &[PathBuf]) -> Result<Merge> {
+        let mut merge = Merge {
+            runs: Vec::with_capacity(paths.len()),
+            heads: BinaryHeap::with_capacity(paths.len()),
+        };
+        for (run, path) in paths.iter().enumerate() {
+            let file =
+                File::open(path).with_context(|| format!("Failed to open {}", path.display()))?;
+            merge.runs.push(BufReader::new(file));
+            merge.advance(run)?;
+        }
+        Ok(merge)
+    }
+
+    /// Reads the next value of `run` into the heap, if it has one.
+    fn advance(&mut self, run: usize) -> Result<()> {
+        let mut bytes = [0; 8];
+        match self.runs[run].read_exact(&mut bytes) {
+            Ok(()) => self.heads.push(Reverse((i64::from_le_bytes(bytes), run))),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => {}
+            Err(e) => return Err(e).context("Failed to read a spilled run"),
+        }
+        Ok(())
+    }
+
+    fn peek(&self) -> Option<i64> {
+        self.heads.peek().map(|&Reverse((v, _))| v)
+    }
+
+    fn next(&mut self) -> Result<Option<i64>> {
+        let Some(Reverse((v, run))) = self.heads.pop() else {
+            return Ok(None);
+        };
+        self.advance(run)?;
+        Ok(Some(v))
+    }
+
+    /// Consumes every value equal to `v` and returns how many there were.
+    fn skip_equal(&mut self, v: i64) -> Result<usize> {
+        let mut count = 0;
+        while self.peek() == Some(v) {
+            self.next()?;
+            count += 1;
+        }
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Day1;
+    use common::{Answer, Lcg, ParseError, Solution};
+    use std::io::Cursor;
+
+    /// Rows drawn from a small range so both lists have plenty of repeats.
+    fn generate(lines: usize, seed: u64) -> String {
+        let mut rng = Lcg::new(seed);
+        (0..lines)
+            .map(|_| format!("{}   {}\n", rng.below(500), rng.below(500)))
+            .collect()
+    }
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("day1-test-{}-{}", name, process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_matches_in_memory() {
+        let dir = scratch_dir("runs");
+        for (lines, run_len) in [(0, 10), (1, 10), (1000, 64), (1000, 1000), (1000, 5000)] {
+            let input = generate(lines, lines as u64);
+            let options = StreamOptions {
+                run_len,
+                temp_dir: dir.clone(),
+            };
+            let totals = stream_totals(Cursor::new(&input), &options).unwrap();
+
+            let parsed = Day1::parse(&input).unwrap();
+            assert_eq!(totals.rows, lines);
+            assert_eq!(
+                (
+                    Answer::from(totals.distance),
+                    Answer::from(totals.similarity)
+                ),
+                (Day1::part1(&parsed).unwrap(), Day1::part2(&parsed).unwrap()),
+                "{} lines in runs of {}",
+                lines,
+                run_len
+            );
+        }
+
+        // every spill cleans up after itself
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_row_errors() {
+        let options = StreamOptions {
+            run_len: 1,
+            temp_dir: scratch_dir("errors"),
+        };
+        let err = stream_totals(Cursor::new("3   4\r\n\n4   x\n"), &options).unwrap_err();
+        let err = err.downcast_ref::<ParseError>().unwrap();
+        assert_eq!((err.line(), err.column(), err.text()), (3, 5, "x"));
+        assert_eq!(fs::read_dir(&options.temp_dir).unwrap().count(), 0);
+    }
+}
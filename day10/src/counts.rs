@@ -0,0 +1,374 @@
+use crate::Island;
+use grid::Point;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrailCount {
+    pub trailhead: Point,
+    /// Distinct peaks reachable from the trailhead.
+    pub score: usize,
+    /// Distinct trails from the trailhead to any peak.
+    pub rating: usize,
+}
+
+/// The square of tiles around a tile that its peaks can be in. A peak is at
+/// most one tile away per step left to climb, so the window's radius is the
+/// longest trail the rules allow.
+struct Window {
+    radius: i32,
+    width: i32,
+    words: usize,
+}
+
+impl Window {
+    fn new(radius: i32) -> Window {
+        let width = 2 * radius + 1;
+        Window {
+            radius,
+            width,
+            words: ((width * width) as usize).div_ceil(64),
+        }
+    }
+
+    /// Bit index of `offset` from the tile that owns the set.
+    fn bit(&self, offset: Point) -> usize {
+        ((offset.y + self.radius) * self.width + offset.x + self.radius) as usize
+    }
+
+    /// How far bits move when a set is re-centred one `step` away.
+    fn shift(&self, step: Point) -> isize {
+        (step.y * self.width + step.x) as isize
+    }
+}
+
+/// ORs `src` into `dst` with every bit moved `shift` places. Moving a window
+/// by `(dx, dy)` is a shift of `dy * width + dx`; peaks never sit near the
+/// window's edge, so no bit wraps onto the wrong row.
+fn or_shifted(dst: &mut [u64], src: &[u64], shift: isize) {
+    let words = src.len() as isize;
+    let word_shift = shift.div_euclid(64);
+    let bit_shift = shift.rem_euclid(64) as u32;
+    let word = |idx: isize| {
+        if (0..words).contains(&idx) {
+            src[idx as usize]
+        } else {
+            0
+        }
+    };
+
+    for (idx, out) in dst.iter_mut().enumerate() {
+        let low = idx as isize - word_shift;
+        let mut moved = word(low) << bit_shift;
+        if bit_shift > 0 {
+            moved |= word(low - 1) >> (64 - bit_shift);
+        }
+        *out |= moved;
+    }
+}
+
+/// Peak sets (`words` per tile) and trail counts for one height's tiles.
+#[derive(Default)]
+struct Layer {
+    sets: Vec<u64>,
+    counts: Vec<usize>,
+}
+
+impl Island {
+    /// Scores and ratings for every trailhead, computed bottom-up from the
+    /// peaks rather than by walking each trail.
+    ///
+    /// Each tile stores the peaks it can reach as a bitset over its `Window`,
+    /// plus its number of trails. A tile's values are the union and sum of
+    /// those of the tiles it can climb to, so heights are processed from the
+    /// top down and a layer is dropped once no lower height can climb to it.
+    pub fn trail_counts(&self) -> Vec<TrailCount> {
+        let rules = &self.rules;
+        let tiles = &self.tiles;
+
+        // with no trailheads or no peaks there is nothing to count, and the
+        // window and layers below are only sized by heights the map has
+        let present = |height| tiles.values().any(|tile| tile.height == Some(height));
+        if !present(rules.start) || !present(rules.end) {
+            return self
+                .trailheads()
+                .map(|trailhead| TrailCount {
+                    trailhead,
+                    score: 0,
+                    rating: 0,
+                })
+                .collect();
+        }
+
+        let window = Window::new(rules.max_steps());
+        let words = window.words;
+
+        // heights with a one tile border that no trail can step onto, so
+        // neighbours never need a bounds check
+        let width = tiles.width() + 2;
+        let mut heights = vec![i32::MIN; width * (tiles.height() + 2)];
+        for (p, tile) in tiles.iter() {
+            if let Some(height) = tile
+                .height
+                .filter(|h| (rules.start..=rules.end).contains(h))
+            {
+                heights[(p.y as usize + 1) * width + p.x as usize + 1] = height;
+            }
+        }
+
+        let layer_count = (rules.end - rules.start + 1) as usize;
+        let mut sizes = vec![0; layer_count];
+        for &height in heights.iter().filter(|&&h| h != i32::MIN) {
+            sizes[(height - rules.start) as usize] += 1;
+        }
+        let mut members: Vec<Vec<u32>> = sizes.into_iter().map(Vec::with_capacity).collect();
+        // position of each tile within its layer, so neighbours can be found
+        let mut slots = vec![u32::MAX; heights.len()];
+        for (idx, &height) in heights.iter().enumerate() {
+            if height != i32::MIN {
+                let layer = &mut members[(height - rules.start) as usize];
+                slots[idx] = layer.len() as u32;
+                layer.push(idx as u32);
+            }
+        }
+
+        // for each move: how it changes a padded index and a window bit
+        let moves: Vec<(isize, isize)> = rules
+            .moves()
+            .iter()
+            .map(|&step| {
+                (
+                    step.y as isize * width as isize + step.x as isize,
+                    window.shift(step),
+                )
+            })
+            .collect();
+        let max_climb = rules.max_climb();
+
+        let mut layers: Vec<Option<Layer>> = (0..layer_count).map(|_| None).collect();
+        // buffers of dropped layers, reused rather than reallocated
+        let mut spare: Vec<Layer> = Vec::new();
+
+        for height in (rules.start..=rules.end).rev() {
+            let members = &members[(height - rules.start) as usize];
+            let mut layer = spare.pop().unwrap_or_default();
+            layer.sets.clear();
+            layer.sets.resize(members.len() * words, 0);
+            layer.counts.clear();
+            layer.counts.resize(members.len(), 0);
+
+            // the layers this height can climb to, indexed by the climb
+            let above: Vec<Option<&Layer>> = (0..=max_climb)
+                .map(|climb| match rules.steps.contains(&climb) {
+                    true => layers
+                        .get((height - rules.start + climb) as usize)?
+                        .as_ref(),
+                    false => None,
+                })
+                .collect();
+
+            for (slot, &idx) in members.iter().enumerate() {
+                let idx = idx as usize;
+                let set = &mut layer.sets[slot * words..(slot + 1) * words];
+                if height == rules.end {
+                    let bit = window.bit(Point::default());
+                    set[bit / 64] |= 1 << (bit % 64);
+                    layer.counts[slot] = 1;
+                    continue;
+                }
+
+                for &(index_step, shift) in &moves {
+                    let next = (idx as isize + index_step) as usize;
+                    let climb = heights[next].saturating_sub(height);
+                    let Some(Some(above)) = above.get(climb as usize) else {
+                        continue;
+                    };
+                    let above_slot = slots[next] as usize;
+                    layer.counts[slot] += above.counts[above_slot];
+                    or_shifted(
+                        set,
+                        &above.sets[above_slot * words..(above_slot + 1) * words],
+                        shift,
+                    );
+                }
+            }
+
+            layers[(height - rules.start) as usize] = Some(layer);
+            // nothing below this height can climb as far as this
+            if let Some(done) = layers
+                .get_mut((height - rules.start + max_climb) as usize)
+                .and_then(Option::take)
+            {
+                spare.push(done);
+            }
+        }
+
+        let trailheads = layers[0].take().unwrap_or_default();
+        members[0]
+            .iter()
+            .enumerate()
+            .map(|(slot, &idx)| TrailCount {
+                trailhead: Point::new(
+                    (idx as usize % width) as i32 - 1,
+                    (idx as usize / width) as i32 - 1,
+                ),
+                score: trailheads.sets[slot * words..(slot + 1) * words]
+                    .iter()
+                    .map(|word| word.count_ones() as usize)
+                    .sum(),
+                rating: trailheads.counts[slot],
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::TrailRules;
+    use common::Lcg;
+    use std::time::{Duration, Instant};
+
+    /// Heights mostly rise by one to the right and downwards, so trails are
+    /// long and heavily shared, with some noise to break them up.
+    fn generate(width: usize, height: usize, seed: u64) -> String {
+        let mut rng = Lcg::new(seed);
+        let mut map = String::with_capacity((width + 1) * height);
+        for y in 0..height {
+            for x in 0..width {
+                let h = if rng.next_u32().is_multiple_of(8) {
+                    rng.next_u32() % 10
+                } else {
+                    ((x + y) % 10) as u32
+                };
+                map.push(char::from_digit(h, 10).unwrap());
+            }
+            map.push('\n');
+        }
+        map
+    }
+
+    /// Like `generate`, but with impassable tiles and heights up to
+    /// `tallest`, written as letters past 9.
+    fn generate_tall(width: usize, height: usize, tallest: u32, seed: u64) -> String {
+        let mut rng = Lcg::new(seed);
+        let mut map = String::with_capacity((width + 1) * height);
+        for y in 0..height {
+            for x in 0..width {
+                let c = match rng.next_u32() % 10 {
+                    0 => '.',
+                    1 => char::from_digit(rng.next_u32() % (tallest + 1), 36).unwrap(),
+                    _ => char::from_digit((x + y) as u32 % (tallest + 1), 36).unwrap(),
+                };
+                map.push(c);
+            }
+            map.push('\n');
+        }
+        map
+    }
+
+    fn assert_matches_recursive_search(island: &Island) {
+        for count in island.trail_counts() {
+            let (score, rating) = island.find_trail_from(count.trailhead);
+            assert_eq!(
+                (count.score, count.rating),
+                (score, rating),
+                "trailhead {} with {:?}",
+                count.trailhead,
+                island.rules()
+            );
+        }
+    }
+
+    #[test]
+    fn test_or_shifted() {
+        let set = |words: &[u64]| {
+            let mut set = [0u64; 3];
+            set[..words.len()].copy_from_slice(words);
+            set
+        };
+
+        let mut dst = set(&[]);
+        or_shifted(&mut dst, &set(&[1]), 70);
+        assert_eq!(dst, set(&[0, 1 << 6]));
+
+        let mut dst = set(&[]);
+        or_shifted(&mut dst, &set(&[0, 1 << 6]), -70);
+        assert_eq!(dst, set(&[1]));
+
+        let mut dst = set(&[]);
+        or_shifted(&mut dst, &set(&[1 << 63]), 1);
+        assert_eq!(dst, set(&[0, 1]));
+    }
+
+    #[test]
+    fn test_matches_recursive_search() {
+        for seed in 0..20 {
+            let island = Island::from_string(&generate(37, 23, seed)).unwrap();
+            assert_matches_recursive_search(&island);
+        }
+    }
+
+    #[test]
+    fn test_matches_recursive_search_with_rules() {
+        let rules = [
+            TrailRules {
+                steps: vec![1, 2],
+                ..TrailRules::default()
+            },
+            TrailRules {
+                diagonal: true,
+                ..TrailRules::default()
+            },
+            TrailRules {
+                start: 2,
+                end: 14,
+                steps: vec![1, 2],
+                diagonal: true,
+            },
+            TrailRules {
+                start: 5,
+                end: 5,
+                ..TrailRules::default()
+            },
+            TrailRules {
+                end: 1000,
+                ..TrailRules::default()
+            },
+        ];
+
+        // the recursive search is exponential in the trail length once steps
+        // can skip a height or go diagonally, so the maps are kept small
+        for seed in 0..6 {
+            for rules in &rules {
+                let tallest = rules.end.clamp(9, 35) as u32;
+                let island =
+                    Island::parse(&generate_tall(13, 9, tallest, seed), rules.clone()).unwrap();
+                assert_matches_recursive_search(&island);
+            }
+        }
+    }
+
+    /// Too slow for debug builds, run with `cargo test --release -- --ignored`.
+    #[test]
+    #[ignore]
+    fn test_large_map() {
+        let (width, height) = (3000, 3000);
+        let island = Island::from_string(&generate(width, height, 2024)).unwrap();
+
+        let start = Instant::now();
+        let counts = island.trail_counts();
+        let elapsed = start.elapsed();
+
+        assert_eq!(
+            counts.len(),
+            island.trailheads().count(),
+            "every trailhead is counted"
+        );
+        assert!(counts.iter().any(|c| c.rating > 100));
+
+        assert!(
+            elapsed < Duration::from_secs(1),
+            "counting took {:?}",
+            elapsed
+        );
+    }
+}
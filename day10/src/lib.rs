@@ -0,0 +1,389 @@
+use anyhow::Result;
+use common::{Answer, ParseError, Solution};
+use grid::{Grid, Point};
+use std::collections::HashSet;
+use std::fmt::Display;
+
+mod counts;
+mod rules;
+
+pub use counts::TrailCount;
+pub use rules::TrailRules;
+
+pub struct Tile {
+    /// `None` for impassable tiles, drawn as `.` in some example maps.
+    pub height: Option<i32>,
+}
+
+impl Tile {
+    /// Heights are digits, or when `letters` is set, also lowercase letters
+    /// from `a` for 10 up to `z` for 35.
+    fn from_char(c: char, letters: bool) -> Option<Self> {
+        let height = match c {
+            '.' => return Some(Tile { height: None }),
+            '0'..='9' => c.to_digit(10),
+            'a'..='z' if letters => c.to_digit(36),
+            _ => None,
+        }?;
+        Some(Tile {
+            height: Some(height as i32),
+        })
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.height.and_then(|h| char::from_digit(h as u32, 36)) {
+            Some(c) => write!(f, "{}", c),
+            None => write!(f, "."),
+        }
+    }
+}
+
+pub struct Island {
+    tiles: Grid<Tile>,
+    rules: TrailRules,
+}
+
+impl Island {
+    pub fn from_string(input: &str) -> Result<Self, ParseError> {
+        Ok(Island {
+            tiles: Grid::parse(input, |c| Tile::from_char(c, false))?,
+            rules: TrailRules::default(),
+        })
+    }
+
+    /// Parses a map for `rules`, which may only use letters for heights if
+    /// its trails end above 9.
+    pub fn parse(input: &str, rules: TrailRules) -> Result<Self> {
+        rules.validate()?;
+        let letters = rules.end > 9;
+        Ok(Island {
+            tiles: Grid::parse(input, |c| Tile::from_char(c, letters))?,
+            rules,
+        })
+    }
+
+    pub fn with_rules(mut self, rules: TrailRules) -> Result<Self> {
+        rules.validate()?;
+        self.rules = rules;
+        Ok(self)
+    }
+
+    pub fn rules(&self) -> &TrailRules {
+        &self.rules
+    }
+
+    pub fn get_tile(&self, p: Point) -> Option<&Tile> {
+        self.tiles.get(p)
+    }
+
+    pub fn trailheads(&self) -> impl Iterator<Item = Point> + '_ {
+        self.tiles
+            .iter()
+            .filter(|(_, tile)| tile.height == Some(self.rules.start))
+            .map(|(p, _)| p)
+    }
+
+    /// The tiles a trail at `p` may step to next.
+    fn climbs_from(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        let height = self.tiles[p].height;
+        self.rules
+            .moves()
+            .iter()
+            .map(move |&step| p + step)
+            .filter(move |&next| match (height, self.tiles.get(next)) {
+                (Some(from), Some(Tile { height: Some(to) })) => self.rules.climbs(from, *to),
+                _ => false,
+            })
+    }
+
+    fn is_peak(&self, p: Point) -> bool {
+        self.tiles[p].height == Some(self.rules.end)
+    }
+
+    /// Walks every path climbing from `p`, recording the peaks reached and
+    /// returning how many distinct paths got there.
+    fn find_trail_recursive(&self, p: Point, peaks: &mut HashSet<Point>) -> usize {
+        if self.is_peak(p) {
+            peaks.insert(p);
+            return 1;
+        }
+
+        self.climbs_from(p)
+            .map(|next| self.find_trail_recursive(next, peaks))
+            .sum()
+    }
+
+    /// The score (distinct peaks reachable) and rating (distinct paths to
+    /// any peak) of the trailhead at `p`.
+    pub fn find_trail_from(&self, p: Point) -> (usize, usize) {
+        let mut peaks = HashSet::new();
+        let rating = self.find_trail_recursive(p, &mut peaks);
+        (peaks.len(), rating)
+    }
+
+    /// Every hiking trail from the trailhead at `p`, each as the points
+    /// visited from the start height up to the end.
+    pub fn trails(&self, p: Point) -> Trails<'_> {
+        let stack = match self.get_tile(p) {
+            Some(tile) if tile.height == Some(self.rules.start) => vec![vec![p]],
+            _ => Vec::new(),
+        };
+        Trails {
+            island: self,
+            stack,
+        }
+    }
+
+    /// Draws the island with `trail` picked out, either in colour or by
+    /// blanking every tile that is not on the trail.
+    pub fn overlay<'a>(&'a self, trail: &'a [Point], style: OverlayStyle) -> TrailOverlay<'a> {
+        TrailOverlay {
+            island: self,
+            trail,
+            style,
+        }
+    }
+
+    pub fn total_score(&self) -> usize {
+        self.trail_counts().iter().map(|count| count.score).sum()
+    }
+
+    pub fn total_rating(&self) -> usize {
+        self.trail_counts().iter().map(|count| count.rating).sum()
+    }
+}
+
+impl Display for Island {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.tiles)
+    }
+}
+
+/// Iterator returned by [`Island::trails`]. Trails come out depth first, in
+/// the order of [`TrailRules::moves`].
+pub struct Trails<'a> {
+    island: &'a Island,
+    stack: Vec<Vec<Point>>,
+}
+
+impl Iterator for Trails<'_> {
+    type Item = Vec<Point>;
+
+    fn next(&mut self) -> Option<Vec<Point>> {
+        while let Some(trail) = self.stack.pop() {
+            let p = *trail.last().unwrap();
+            if self.island.is_peak(p) {
+                return Some(trail);
+            }
+
+            let steps: Vec<Point> = self.island.climbs_from(p).collect();
+            // pushed in reverse so the first neighbour is explored first
+            for next in steps.into_iter().rev() {
+                let mut extended = trail.clone();
+                extended.push(next);
+                self.stack.push(extended);
+            }
+        }
+        None
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum OverlayStyle {
+    /// Tiles off the trail are shown as `.`.
+    Ascii,
+    /// Tiles on the trail are bold green, the rest are dimmed.
+    Ansi,
+}
+
+pub struct TrailOverlay<'a> {
+    island: &'a Island,
+    trail: &'a [Point],
+    style: OverlayStyle,
+}
+
+impl Display for TrailOverlay<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (p, tile) in self.island.tiles.iter() {
+            let on_trail = self.trail.contains(&p);
+            match (self.style, on_trail) {
+                (OverlayStyle::Ascii, true) => write!(f, "{}", tile)?,
+                (OverlayStyle::Ascii, false) => write!(f, ".")?,
+                (OverlayStyle::Ansi, true) => write!(f, "\x1b[1;32m{}\x1b[0m", tile)?,
+                (OverlayStyle::Ansi, false) => write!(f, "\x1b[2m{}\x1b[0m", tile)?,
+            }
+            if p.x as usize == self.island.tiles.width() - 1 {
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Parsed = Island;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(Island::from_string(input)?)
+    }
+
+    fn part1(island: &Self::Parsed) -> Result<Answer> {
+        Ok(island.total_score().into())
+    }
+
+    fn part2(island: &Self::Parsed) -> Result<Answer> {
+        Ok(island.total_rating().into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732
+";
+
+    #[test]
+    fn test_single_trail() {
+        let island = Island::from_string("0123\n1234\n8765\n9876\n").unwrap();
+        assert_eq!(island.find_trail_from(Point::new(0, 0)), (1, 16));
+    }
+
+    #[test]
+    fn test_example_trailheads() {
+        let island = Island::from_string(EXAMPLE).unwrap();
+        let scores: Vec<usize> = island
+            .trailheads()
+            .map(|p| island.find_trail_from(p).0)
+            .collect();
+        assert_eq!(scores, [5, 6, 5, 3, 1, 3, 5, 3, 5]);
+
+        let ratings: Vec<usize> = island
+            .trailheads()
+            .map(|p| island.find_trail_from(p).1)
+            .collect();
+        assert_eq!(ratings, [20, 24, 10, 4, 1, 4, 5, 8, 5]);
+    }
+
+    #[test]
+    fn test_trails_match_rating() {
+        let island = Island::from_string(EXAMPLE).unwrap();
+        for trailhead in island.trailheads() {
+            let trails: Vec<Vec<Point>> = island.trails(trailhead).collect();
+            let (score, rating) = island.find_trail_from(trailhead);
+            assert_eq!(trails.len(), rating);
+
+            let peaks: HashSet<Point> = trails.iter().map(|t| t[9]).collect();
+            assert_eq!(peaks.len(), score);
+
+            for trail in &trails {
+                assert_eq!(trail.len(), 10);
+                for (height, pair) in trail.windows(2).enumerate() {
+                    let step = pair[1] - pair[0];
+                    assert!(Point::ORTHOGONAL.contains(&step));
+                    assert_eq!(
+                        island.get_tile(pair[1]).unwrap().height,
+                        Some(height as i32 + 1)
+                    );
+                }
+            }
+        }
+
+        // not a trailhead
+        assert_eq!(island.trails(Point::new(0, 0)).count(), 0);
+    }
+
+    #[test]
+    fn test_overlay() {
+        let island = Island::from_string("0123\n1234\n8765\n9876\n").unwrap();
+        let trail = island.trails(Point::new(0, 0)).next().unwrap();
+        assert_eq!(
+            island.overlay(&trail, OverlayStyle::Ascii).to_string(),
+            "0123\n...4\n...5\n9876\n"
+        );
+
+        let ansi = island.overlay(&trail, OverlayStyle::Ansi).to_string();
+        assert!(ansi.starts_with("\x1b[1;32m0\x1b[0m\x1b[1;32m1\x1b[0m"));
+        assert!(ansi.contains("\n\x1b[2m1\x1b[0m"));
+    }
+
+    #[test]
+    fn test_impassable_tiles() {
+        let island =
+            Island::from_string("...0...\n...1...\n...2...\n6543456\n7.....7\n8.....8\n9.....9\n")
+                .unwrap();
+        assert_eq!(island.find_trail_from(Point::new(3, 0)), (2, 2));
+        assert!(island.get_tile(Point::new(0, 0)).unwrap().height.is_none());
+
+        let island =
+            Island::from_string(".....0.\n..4321.\n..5..2.\n..6543.\n..7..4.\n..8765.\n..9....\n")
+                .unwrap();
+        assert_eq!(island.total_rating(), 3);
+        assert_eq!(island.trails(Point::new(5, 0)).count(), 3);
+    }
+
+    #[test]
+    fn test_rules() {
+        let island = Island::from_string("0123\n1234\n2345\n3456\n").unwrap();
+
+        // the diagonal from 0 only climbs by two at a time
+        let rules = TrailRules {
+            steps: vec![2],
+            end: 6,
+            diagonal: true,
+            ..TrailRules::default()
+        };
+        let island = island.with_rules(rules).unwrap();
+        assert_eq!(island.find_trail_from(Point::new(0, 0)), (1, 1));
+        let trail: Vec<Point> = island.trails(Point::new(0, 0)).next().unwrap();
+        assert_eq!(
+            trail,
+            [
+                Point::new(0, 0),
+                Point::new(1, 1),
+                Point::new(2, 2),
+                Point::new(3, 3)
+            ]
+        );
+
+        let err = Island::from_string("0123\n")
+            .unwrap()
+            .with_rules(TrailRules {
+                steps: vec![-1],
+                ..TrailRules::default()
+            });
+        assert!(err.is_err());
+
+        let tall = TrailRules {
+            end: 11,
+            ..TrailRules::default()
+        };
+        let island = Island::parse("9ab\n", tall).unwrap();
+        assert_eq!(island.to_string(), "9ab\n");
+        assert!(Island::parse("9aB\n", TrailRules::default()).is_err());
+
+        // letters are typos unless the rules climb past 9
+        let err = Island::from_string("01x\n").err().unwrap();
+        assert_eq!(err.message(), "invalid character");
+        assert_eq!(err.column(), 3);
+    }
+
+    #[test]
+    fn test_example_totals() {
+        let island = Island::from_string(EXAMPLE).unwrap();
+        assert_eq!(island.total_score(), 36);
+        assert_eq!(island.total_rating(), 81);
+    }
+}
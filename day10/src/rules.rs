@@ -0,0 +1,106 @@
+use anyhow::{anyhow, Result};
+use grid::Point;
+
+/// What counts as a hiking trail: where it starts and ends, how much each
+/// step may climb, and whether it may move diagonally.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrailRules {
+    /// Height gained by each step, any of these is allowed.
+    pub steps: Vec<i32>,
+    pub start: i32,
+    pub end: i32,
+    /// Allow all eight neighbours rather than only the four sharing an edge.
+    pub diagonal: bool,
+}
+
+impl Default for TrailRules {
+    /// The puzzle's rules: 0 to 9, one height at a time, no diagonals.
+    fn default() -> Self {
+        TrailRules {
+            steps: vec![1],
+            start: 0,
+            end: 9,
+            diagonal: false,
+        }
+    }
+}
+
+impl TrailRules {
+    pub fn validate(&self) -> Result<()> {
+        if self.steps.is_empty() {
+            return Err(anyhow!("At least one step height is needed"));
+        }
+        if let Some(step) = self.steps.iter().find(|&&step| step <= 0) {
+            return Err(anyhow!("Step heights must climb, got {}", step));
+        }
+        if self.start > self.end {
+            return Err(anyhow!(
+                "Trails start at {} but end lower, at {}",
+                self.start,
+                self.end
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn moves(&self) -> &'static [Point] {
+        if self.diagonal {
+            &Point::ALL
+        } else {
+            &Point::ORTHOGONAL
+        }
+    }
+
+    /// Whether a trail may step from a tile at `from` to one at `to`.
+    pub fn climbs(&self, from: i32, to: i32) -> bool {
+        to <= self.end && self.steps.contains(&(to - from))
+    }
+
+    /// The most steps any trail can take, which is also the furthest a peak
+    /// can be from its trailhead in either direction.
+    pub fn max_steps(&self) -> i32 {
+        let smallest = self.steps.iter().min().copied().unwrap_or(1);
+        (self.end - self.start) / smallest
+    }
+
+    pub fn max_climb(&self) -> i32 {
+        self.steps.iter().max().copied().unwrap_or(1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_validate() {
+        assert!(TrailRules::default().validate().is_ok());
+
+        let rules = TrailRules {
+            steps: vec![1, 0],
+            ..TrailRules::default()
+        };
+        assert!(rules.validate().is_err());
+
+        let rules = TrailRules {
+            start: 9,
+            end: 0,
+            ..TrailRules::default()
+        };
+        assert!(rules.validate().is_err());
+    }
+
+    #[test]
+    fn test_climbs() {
+        let rules = TrailRules {
+            steps: vec![1, 2],
+            ..TrailRules::default()
+        };
+        assert!(rules.climbs(3, 4));
+        assert!(rules.climbs(3, 5));
+        assert!(!rules.climbs(3, 6));
+        assert!(!rules.climbs(8, 10));
+        assert_eq!(rules.max_steps(), 9);
+        assert_eq!(rules.max_climb(), 2);
+    }
+}
@@ -0,0 +1,119 @@
+use crate::Policy;
+
+/// What the Problem Dampener makes of a report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dampened {
+    /// Safe as it is.
+    Safe,
+    /// Safe once the level at this index is removed.
+    Remove(usize),
+    /// Unsafe even with a level removed.
+    Unsafe,
+}
+
+impl Dampened {
+    pub fn is_safe(&self) -> bool {
+        !matches!(self, Dampened::Unsafe)
+    }
+}
+
+impl Policy {
+    /// Whether removing at most one level makes a report safe, and which
+    /// level to remove, in time linear in the report's length. Ignores
+    /// `max_removals`.
+    ///
+    /// For each direction, the first pair of levels that doesn't make an
+    /// allowed step has to lose one of its two levels, as removing any other
+    /// leaves the pair next to each other. So only those two removals are
+    /// tried, each checked in one pass over the report.
+    pub fn dampen(&self, levels: &[i32]) -> Dampened {
+        let mut removal = None;
+        for &direction in self.directions() {
+            let Some(bad) = self.first_bad_pair(levels, direction, None) else {
+                return Dampened::Safe;
+            };
+            if removal.is_none() {
+                removal = [bad, bad + 1]
+                    .into_iter()
+                    .find(|&skip| self.first_bad_pair(levels, direction, Some(skip)).is_none());
+            }
+        }
+        removal.map_or(Dampened::Unsafe, Dampened::Remove)
+    }
+
+    /// Index of the first level whose step to the next one isn't allowed,
+    /// ignoring the level at `skip`.
+    fn first_bad_pair(&self, levels: &[i32], direction: i32, skip: Option<usize>) -> Option<usize> {
+        let mut kept = (0..levels.len()).filter(|&idx| Some(idx) != skip);
+        let mut prev = kept.next()?;
+        for idx in kept {
+            if !self.allows_step(levels[prev], levels[idx], direction) {
+                return Some(prev);
+            }
+            prev = idx;
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// The original dampener: try removing each level in turn.
+    fn dampen_brute_force(levels: &[i32]) -> Option<Vec<usize>> {
+        let policy = Policy::default();
+        if policy.check(levels).is_safe() {
+            return Some(Vec::new());
+        }
+        let removable: Vec<usize> = (0..levels.len())
+            .filter(|&idx| {
+                let mut copy = levels.to_vec();
+                copy.remove(idx);
+                policy.check(&copy).is_safe()
+            })
+            .collect();
+        (!removable.is_empty()).then_some(removable)
+    }
+
+    fn dampen(levels: &[i32]) -> Dampened {
+        Policy::default().dampen(levels)
+    }
+
+    #[test]
+    fn test_example_reports() {
+        assert_eq!(dampen(&[7, 6, 4, 2, 1]), Dampened::Safe);
+        assert_eq!(dampen(&[1, 2, 7, 8, 9]), Dampened::Unsafe);
+        assert_eq!(dampen(&[9, 7, 6, 2, 1]), Dampened::Unsafe);
+        assert_eq!(dampen(&[1, 3, 2, 4, 5]), Dampened::Remove(1));
+        assert_eq!(dampen(&[8, 6, 4, 4, 1]), Dampened::Remove(2));
+        assert_eq!(dampen(&[1, 3, 6, 7, 9]), Dampened::Safe);
+    }
+
+    #[test]
+    fn test_removes_either_end() {
+        assert_eq!(dampen(&[9, 1, 2, 3]), Dampened::Remove(0));
+        assert_eq!(dampen(&[1, 2, 3, 9]), Dampened::Remove(3));
+        assert_eq!(dampen(&[5, 1]), Dampened::Remove(0));
+        assert_eq!(dampen(&[5]), Dampened::Safe);
+    }
+
+    proptest! {
+        #[test]
+        fn test_matches_brute_force(levels in prop::collection::vec(0..12, 0..9)) {
+            match (dampen(&levels), dampen_brute_force(&levels)) {
+                (Dampened::Safe, Some(removable)) => prop_assert!(removable.is_empty()),
+                (Dampened::Remove(idx), Some(removable)) => prop_assert!(removable.contains(&idx)),
+                (Dampened::Unsafe, None) => {}
+                (linear, brute_force) => prop_assert!(
+                    false,
+                    "{:?} gave {:?}, brute force {:?}",
+                    levels,
+                    linear,
+                    brute_force
+                ),
+            }
+        }
+    }
+}
@@ -0,0 +1,198 @@
+use crate::{Dampened, Policy, Violation};
+use serde::Serialize;
+use std::fmt::Display;
+
+/// Why a report is safe or not under a policy.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Explanation {
+    pub levels: Vec<i32>,
+    /// Rules broken before any levels are removed.
+    pub violations: Vec<Violation>,
+    /// Indices of the levels removed to make the report safe. Empty if it
+    /// was safe already, or if removing as many as the policy allows isn't
+    /// enough.
+    pub removed: Vec<usize>,
+    pub safe: bool,
+}
+
+impl Policy {
+    pub fn explain(&self, levels: &[i32]) -> Explanation {
+        let violations = self.check(levels).violations;
+        let mut removed = Vec::new();
+        let mut safe = violations.is_empty();
+        if !safe && self.max_removals == 1 {
+            // report the same level the Problem Dampener would remove
+            if let Dampened::Remove(idx) = self.dampen(levels) {
+                removed = vec![idx];
+                safe = true;
+            }
+        } else if !safe && self.max_removals > 1 {
+            let removals = self.removals(levels);
+            if removals.len() <= self.max_removals {
+                removed = removals;
+                safe = true;
+            }
+        }
+        Explanation {
+            levels: levels.to_vec(),
+            violations,
+            removed,
+            safe,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExplainStyle {
+    /// Broken rules are only underlined.
+    Plain,
+    /// Levels are green, or red in a pair that breaks a rule, and removed
+    /// levels are struck through.
+    Ansi,
+}
+
+impl Explanation {
+    pub fn render(&self, style: ExplainStyle) -> RenderedExplanation<'_> {
+        RenderedExplanation {
+            explanation: self,
+            style,
+        }
+    }
+}
+
+pub struct RenderedExplanation<'a> {
+    explanation: &'a Explanation,
+    style: ExplainStyle,
+}
+
+/// The step between two levels as an arrow and its size, such as `↗2`.
+fn arrow(from: i32, to: i32) -> String {
+    let arrow = match to.cmp(&from) {
+        std::cmp::Ordering::Greater => '↗',
+        std::cmp::Ordering::Less => '↘',
+        std::cmp::Ordering::Equal => '→',
+    };
+    format!("{}{}", arrow, from.abs_diff(to))
+}
+
+impl Display for RenderedExplanation<'_> {
+    /// The levels with the steps between them, then a line underlining each
+    /// broken rule, then the outcome.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Explanation {
+            levels,
+            violations,
+            removed,
+            safe,
+        } = self.explanation;
+        let paint = |text: &str, code: &str| match self.style {
+            ExplainStyle::Plain => text.to_string(),
+            ExplainStyle::Ansi => format!("\x1b[{}m{}\x1b[0m", code, text),
+        };
+        let broken = |pair: usize| violations.iter().any(|v| v.index == pair);
+
+        // character column each level starts at, for the underlines
+        let mut columns = Vec::with_capacity(levels.len());
+        let mut width = 0;
+        for (idx, &level) in levels.iter().enumerate() {
+            if idx > 0 {
+                let step = arrow(levels[idx - 1], level);
+                let code = if broken(idx - 1) { "1;31" } else { "2" };
+                write!(f, " {} ", paint(&step, code))?;
+                width += step.chars().count() + 2;
+            }
+            let text = level.to_string();
+            let code = if removed.contains(&idx) {
+                "9;33"
+            } else if (idx > 0 && broken(idx - 1)) || broken(idx) {
+                "31"
+            } else {
+                "32"
+            };
+            write!(f, "{}", paint(&text, code))?;
+            columns.push((width, width + text.len()));
+            width += text.len();
+        }
+        writeln!(f)?;
+
+        for violation in violations {
+            let (start, _) = columns[violation.index];
+            let (_, end) = columns[violation.index + 1];
+            writeln!(
+                f,
+                "{}{} {}",
+                " ".repeat(start),
+                paint(&"^".repeat(end - start), "1;31"),
+                violation.rule
+            )?;
+        }
+
+        let removed: Vec<String> = removed
+            .iter()
+            .map(|&idx| format!("{} at index {}", levels[idx], idx))
+            .collect();
+        match (safe, removed.is_empty()) {
+            (true, true) => writeln!(f, "{}", paint("safe", "32")),
+            (true, false) => writeln!(
+                f,
+                "{} after removing {}",
+                paint("safe", "32"),
+                removed.join(", ")
+            ),
+            (false, _) => writeln!(f, "{}", paint("unsafe", "31")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Rule;
+
+    #[test]
+    fn test_explain() {
+        let policy = Policy::dampened();
+        let explanation = policy.explain(&[1, 3, 2, 4, 5]);
+        assert_eq!(
+            explanation.violations,
+            [Violation {
+                index: 1,
+                rule: Rule::DirectionChange
+            }]
+        );
+        assert!(explanation.safe);
+        assert_eq!(explanation.removed.len(), 1);
+
+        let explanation = policy.explain(&[1, 2, 7, 8, 9]);
+        assert!(!explanation.safe && explanation.removed.is_empty());
+    }
+
+    #[test]
+    fn test_render() {
+        let policy = Policy::dampened();
+        assert_eq!(
+            policy
+                .explain(&[8, 6, 4, 4, 1])
+                .render(ExplainStyle::Plain)
+                .to_string(),
+            "8 ↘2 6 ↘2 4 →0 4 ↘3 1\n\
+             \x20         ^^^^^^ zero step\n\
+             safe after removing 4 at index 2\n"
+        );
+        assert_eq!(
+            policy
+                .explain(&[9, 7, 6, 2, 1])
+                .render(ExplainStyle::Plain)
+                .to_string(),
+            "9 ↘2 7 ↘1 6 ↘4 2 ↘1 1\n\
+             \x20         ^^^^^^ step too large\n\
+             unsafe\n"
+        );
+
+        let ansi = policy
+            .explain(&[1, 5])
+            .render(ExplainStyle::Ansi)
+            .to_string();
+        assert!(ansi.contains("\x1b[1;31m↗4\x1b[0m"), "{:?}", ansi);
+    }
+}
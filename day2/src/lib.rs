@@ -0,0 +1,140 @@
+use anyhow::Result;
+use common::{parse_number, Answer, ParseError, Solution};
+
+mod dampener;
+mod explain;
+mod policy;
+mod safety;
+mod stream;
+
+pub use dampener::Dampened;
+pub use explain::{ExplainStyle, Explanation, RenderedExplanation};
+pub use policy::{Monotonicity, Policy};
+pub use safety::{Rule, Verdict, Violation};
+pub use stream::{stream_tally, StreamOptions, Tally};
+
+/// Reads one report's levels into `levels`, which is cleared first so the
+/// buffer can be reused from report to report.
+fn parse_report(line: &str, levels: &mut Vec<i32>) -> Result<(), ParseError> {
+    levels.clear();
+    for num in line.split_whitespace() {
+        levels.push(parse_number(line, num)?);
+    }
+    if levels.is_empty() {
+        return Err(ParseError::at(line, line, "expected at least one level"));
+    }
+    Ok(())
+}
+
+fn parse_data(content: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    let mut data = Vec::new();
+    for (idx, line) in content.lines().enumerate() {
+        let mut row = Vec::new();
+        parse_report(line, &mut row).map_err(|e| e.offset_lines(idx))?;
+        data.push(row);
+    }
+    Ok(data)
+}
+
+/// Counts the safe and unsafe reports, failing if `policy` isn't valid.
+fn summarise(data: &[Vec<i32>], policy: &Policy) -> Result<(i32, i32)> {
+    policy.validate()?;
+    let mut safe_count = 0;
+    let mut unsafe_count = 0;
+
+    for row in data {
+        if policy.is_safe(row) {
+            safe_count += 1;
+        } else {
+            unsafe_count += 1;
+        }
+    }
+    Ok((safe_count, unsafe_count))
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Parsed = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(parse_data(input)?)
+    }
+
+    fn part1(data: &Self::Parsed) -> Result<Answer> {
+        let (safe_count, _) = summarise(data, &Policy::default())?;
+        Ok(safe_count.into())
+    }
+
+    fn part2(data: &Self::Parsed) -> Result<Answer> {
+        let (safe_count, _) = summarise(data, &Policy::dampened())?;
+        Ok(safe_count.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_increasing() {
+        let row = vec![1, 2, 3, 4, 5];
+        assert!(Policy::default().is_safe(&row));
+    }
+
+    #[test]
+    fn test_decreasing() {
+        let row = vec![5, 4, 3, 2, 1];
+        assert!(Policy::default().is_safe(&row));
+    }
+
+    #[test]
+    fn test_summarise() {
+        let data = vec![vec![1, 2, 3, 4, 5], vec![5, 4, 3, 2, 1]];
+        let (safe_count, unsafe_count) = summarise(&data, &Policy::default()).unwrap();
+        assert_eq!(safe_count, 2);
+        assert_eq!(unsafe_count, 0);
+    }
+
+    #[test]
+    fn test_expected_results() {
+        let expected_safe = 4;
+        let expected_unsafe = 2;
+
+        let example_data = vec![
+            vec![7, 6, 4, 2, 1],
+            vec![1, 2, 7, 8, 9],
+            vec![9, 7, 6, 2, 1],
+            vec![1, 3, 2, 4, 5],
+            vec![8, 6, 4, 4, 1],
+            vec![1, 3, 6, 7, 9],
+        ];
+
+        let (safe_count, unsafe_count) = summarise(&example_data, &Policy::dampened()).unwrap();
+        assert_eq!(safe_count, expected_safe);
+        assert_eq!(unsafe_count, expected_unsafe);
+    }
+
+    #[test]
+    fn test_summarise_rejects_invalid_policy() {
+        let policy = Policy {
+            min_step: 3,
+            max_step: 1,
+            ..Policy::default()
+        };
+        let err = summarise(&[vec![1, 2, 3]], &policy).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "The minimum step 3 is larger than the maximum step 1"
+        );
+    }
+
+    #[test]
+    fn test_parse_error_location() {
+        let err = parse_data("7 6 4\n1 2 x 8\n").unwrap_err();
+        assert_eq!((err.line(), err.column(), err.text()), (2, 5, "x"));
+
+        let err = parse_data("7 6 4\n\n1 2\n").unwrap_err();
+        assert_eq!((err.line(), err.column()), (2, 1));
+    }
+}
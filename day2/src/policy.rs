@@ -0,0 +1,257 @@
+use anyhow::{anyhow, Result};
+
+/// Which way a report's levels have to go.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Monotonicity {
+    Increasing,
+    Decreasing,
+    /// Either way, as long as every step goes the same way.
+    Either,
+}
+
+/// What makes a report safe: how far each step may move and in which
+/// direction, and how many levels may be removed to get there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Policy {
+    pub min_step: i32,
+    pub max_step: i32,
+    pub monotonicity: Monotonicity,
+    /// Levels that may be removed from a report to make it safe.
+    pub max_removals: usize,
+}
+
+impl Default for Policy {
+    /// The puzzle's first part: steps of 1 to 3 either way, nothing removed.
+    fn default() -> Self {
+        Policy {
+            min_step: 1,
+            max_step: 3,
+            monotonicity: Monotonicity::Either,
+            max_removals: 0,
+        }
+    }
+}
+
+impl Policy {
+    /// The puzzle's second part, where the Problem Dampener may remove a
+    /// level.
+    pub fn dampened() -> Policy {
+        Policy {
+            max_removals: 1,
+            ..Policy::default()
+        }
+    }
+
+    /// Checks the step bounds can be met: `summarise` and `stream_tally`
+    /// refuse a policy that fails this rather than finding nothing safe.
+    pub fn validate(&self) -> Result<()> {
+        if self.min_step < 0 {
+            return Err(anyhow!(
+                "The minimum step can't be negative, got {}",
+                self.min_step
+            ));
+        }
+        if self.min_step > self.max_step {
+            return Err(anyhow!(
+                "The minimum step {} is larger than the maximum step {}",
+                self.min_step,
+                self.max_step
+            ));
+        }
+        Ok(())
+    }
+
+    /// The directions a report may go in, `1` for increasing.
+    pub(crate) fn directions(&self) -> &'static [i32] {
+        match self.monotonicity {
+            Monotonicity::Increasing => &[1],
+            Monotonicity::Decreasing => &[-1],
+            Monotonicity::Either => &[1, -1],
+        }
+    }
+
+    /// Whether going from `from` to `to` is an allowed step in `direction`.
+    /// Worked out in `i64`, like `check`, as the step may not fit in `i32`.
+    pub(crate) fn allows_step(&self, from: i32, to: i32, direction: i32) -> bool {
+        let step = (i64::from(to) - i64::from(from)) * i64::from(direction);
+        (i64::from(self.min_step)..=i64::from(self.max_step)).contains(&step)
+    }
+
+    pub fn is_safe(&self, levels: &[i32]) -> bool {
+        match self.max_removals {
+            0 => self.check(levels).is_safe(),
+            1 => self.dampen(levels).is_safe(),
+            max_removals => self.min_removals(levels) <= max_removals,
+        }
+    }
+
+    /// The fewest levels that have to be removed to make a report safe.
+    pub fn min_removals(&self, levels: &[i32]) -> usize {
+        self.removals(levels).len()
+    }
+
+    /// Indices of a smallest set of levels whose removal makes a report safe,
+    /// in ascending order.
+    ///
+    /// For each direction, `kept[i]` is the fewest removals that leave a safe
+    /// report ending at level `i`, either by removing everything before it or
+    /// by following on from an earlier kept level `j` it can step to and
+    /// removing everything in between. Quadratic in the report's length,
+    /// which is fine for reports of a handful of levels.
+    pub fn removals(&self, levels: &[i32]) -> Vec<usize> {
+        let n = levels.len();
+        let mut best: Option<(usize, Vec<Option<usize>>, usize)> = None;
+
+        for &direction in self.directions() {
+            let mut kept = vec![0; n];
+            let mut previous = vec![None; n];
+            for i in 0..n {
+                kept[i] = i;
+                for j in 0..i {
+                    let removed = kept[j] + (i - j - 1);
+                    if removed < kept[i] && self.allows_step(levels[j], levels[i], direction) {
+                        kept[i] = removed;
+                        previous[i] = Some(j);
+                    }
+                }
+            }
+
+            let end = (0..n).min_by_key(|&i| kept[i] + (n - 1 - i));
+            if let Some(end) = end {
+                let removed = kept[end] + (n - 1 - end);
+                if best.as_ref().is_none_or(|&(fewest, _, _)| removed < fewest) {
+                    best = Some((removed, previous, end));
+                }
+            }
+        }
+
+        let Some((_, previous, end)) = best else {
+            return Vec::new();
+        };
+        let mut keep = vec![false; n];
+        let mut at = Some(end);
+        while let Some(i) = at {
+            keep[i] = true;
+            at = previous[i];
+        }
+        (0..n).filter(|&i| !keep[i]).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Tries removing every subset of the levels and keeps the smallest that
+    /// leaves a safe report.
+    fn min_removals_brute_force(policy: &Policy, levels: &[i32]) -> usize {
+        let n = levels.len();
+        (0..1usize << n)
+            .filter(|mask| {
+                let kept: Vec<i32> = (0..n)
+                    .filter(|i| mask & (1 << i) == 0)
+                    .map(|i| levels[i])
+                    .collect();
+                policy.check(&kept).is_safe()
+            })
+            .map(|mask| mask.count_ones() as usize)
+            .min()
+            .unwrap_or(n)
+    }
+
+    #[test]
+    fn test_example_removals() {
+        let policy = Policy::default();
+        assert_eq!(policy.removals(&[7, 6, 4, 2, 1]), []);
+        assert_eq!(policy.removals(&[1, 2, 7, 8, 9]), [0, 1]);
+        // either of the middle two levels can go
+        assert_eq!(policy.min_removals(&[1, 3, 2, 4, 5]), 1);
+        assert_eq!(policy.min_removals(&[8, 6, 4, 4, 1]), 1);
+        assert_eq!(policy.removals(&[]), []);
+    }
+
+    #[test]
+    fn test_step_bounds_and_direction() {
+        let policy = Policy {
+            min_step: 2,
+            max_step: 5,
+            monotonicity: Monotonicity::Decreasing,
+            max_removals: 2,
+        };
+        assert!(policy.validate().is_ok());
+        assert!(policy.is_safe(&[20, 15, 13, 9]));
+        // 15 to 14 is too small a step
+        assert_eq!(policy.removals(&[20, 15, 14, 12]), [2]);
+        assert_eq!(policy.min_removals(&[1, 3, 5, 7]), 3);
+        assert!(!policy.is_safe(&[1, 3, 5, 7]));
+
+        let plateaus = Policy {
+            min_step: 0,
+            ..Policy::default()
+        };
+        assert!(plateaus.is_safe(&[1, 1, 2, 2]));
+
+        let backwards = Policy {
+            min_step: 3,
+            max_step: 1,
+            ..Policy::default()
+        };
+        assert!(backwards.validate().is_err());
+    }
+
+    #[test]
+    fn test_extreme_levels() {
+        let policy = Policy {
+            max_removals: 2,
+            ..Policy::default()
+        };
+        let levels = [i32::MAX, i32::MIN, i32::MAX, i32::MIN];
+        assert_eq!(policy.min_removals(&levels), 3);
+        assert!(!policy.is_safe(&levels));
+        assert!(!Policy::dampened().is_safe(&levels));
+
+        // with steps up to i32::MAX, only the step that overflows i32 is too large
+        let wide = Policy {
+            max_step: i32::MAX,
+            monotonicity: Monotonicity::Decreasing,
+            ..Policy::default()
+        };
+        assert!(!wide.is_safe(&[i32::MAX, i32::MIN]));
+        assert!(wide.is_safe(&[i32::MAX, 0]));
+    }
+
+    proptest! {
+        #[test]
+        fn test_matches_brute_force(
+            levels in prop::collection::vec(0..12, 0..9),
+            min_step in 0..3,
+            extra in 0..4,
+            monotonicity in prop::sample::select(vec![
+                Monotonicity::Increasing,
+                Monotonicity::Decreasing,
+                Monotonicity::Either,
+            ]),
+        ) {
+            let policy = Policy {
+                min_step,
+                max_step: min_step + extra,
+                monotonicity,
+                max_removals: 0,
+            };
+            let removals = policy.removals(&levels);
+            prop_assert_eq!(removals.len(), min_removals_brute_force(&policy, &levels));
+
+            let kept: Vec<i32> = (0..levels.len())
+                .filter(|i| !removals.contains(i))
+                .map(|i| levels[i])
+                .collect();
+            prop_assert!(policy.check(&kept).is_safe());
+
+            for max_removals in 0..3 {
+                let policy = Policy { max_removals, ..policy.clone() };
+                prop_assert_eq!(policy.is_safe(&levels), removals.len() <= max_removals);
+            }
+        }
+    }
+}
@@ -0,0 +1,185 @@
+use crate::{Monotonicity, Policy};
+use serde::Serialize;
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Rule {
+    /// The pair goes the opposite way to the rest of the report.
+    DirectionChange,
+    /// The pair's levels are equal.
+    ZeroStep,
+    /// The pair's levels differ by less than the policy's minimum step.
+    StepTooSmall,
+    /// The pair's levels differ by more than the policy's maximum step.
+    StepTooLarge,
+}
+
+impl Display for Rule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Rule::DirectionChange => write!(f, "direction change"),
+            Rule::ZeroStep => write!(f, "zero step"),
+            Rule::StepTooSmall => write!(f, "step too small"),
+            Rule::StepTooLarge => write!(f, "step too large"),
+        }
+    }
+}
+
+/// A rule broken by the levels at `index` and `index + 1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Violation {
+    pub index: usize,
+    pub rule: Rule,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Verdict {
+    /// Every broken rule, in order along the report. A pair can break both
+    /// `DirectionChange` and `StepTooLarge`.
+    pub violations: Vec<Violation>,
+}
+
+impl Verdict {
+    pub fn is_safe(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+impl Policy {
+    /// Checks every adjacent pair of a report, without removing any levels.
+    /// Unless the policy fixes it, a report's direction is taken to be the
+    /// one most of its steps go in, or that of its first step on a tie, and
+    /// the pairs going the other way are the ones reported as changing
+    /// direction.
+    pub fn check(&self, levels: &[i32]) -> Verdict {
+        // levels can be anywhere in `i32`, so a step between them may not fit
+        let steps = || {
+            levels
+                .windows(2)
+                .map(|pair| i64::from(pair[1]) - i64::from(pair[0]))
+        };
+        let direction = match self.monotonicity {
+            Monotonicity::Increasing => 1,
+            Monotonicity::Decreasing => -1,
+            Monotonicity::Either => {
+                let rising = steps().filter(|&step| step > 0).count();
+                let falling = steps().filter(|&step| step < 0).count();
+                match rising.cmp(&falling) {
+                    std::cmp::Ordering::Greater => 1,
+                    std::cmp::Ordering::Less => -1,
+                    std::cmp::Ordering::Equal => {
+                        steps().map(i64::signum).find(|&s| s != 0).unwrap_or(1)
+                    }
+                }
+            }
+        };
+
+        let mut violations = Vec::new();
+        for (index, step) in steps().enumerate() {
+            let mut broken = |rule| violations.push(Violation { index, rule });
+            if step == 0 {
+                if self.min_step > 0 {
+                    broken(Rule::ZeroStep);
+                }
+                continue;
+            }
+            if step.signum() != direction {
+                broken(Rule::DirectionChange);
+            }
+            if step.abs() < i64::from(self.min_step) {
+                broken(Rule::StepTooSmall);
+            }
+            if step.abs() > i64::from(self.max_step) {
+                broken(Rule::StepTooLarge);
+            }
+        }
+        Verdict { violations }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn violations(levels: &[i32]) -> Vec<(usize, Rule)> {
+        Policy::default()
+            .check(levels)
+            .violations
+            .iter()
+            .map(|v| (v.index, v.rule))
+            .collect()
+    }
+
+    #[test]
+    fn test_example_reports() {
+        assert_eq!(violations(&[7, 6, 4, 2, 1]), []);
+        assert_eq!(violations(&[1, 2, 7, 8, 9]), [(1, Rule::StepTooLarge)]);
+        assert_eq!(violations(&[9, 7, 6, 2, 1]), [(2, Rule::StepTooLarge)]);
+        assert_eq!(violations(&[1, 3, 2, 4, 5]), [(1, Rule::DirectionChange)]);
+        assert_eq!(violations(&[8, 6, 4, 4, 1]), [(2, Rule::ZeroStep)]);
+        assert_eq!(violations(&[1, 3, 6, 7, 9]), []);
+    }
+
+    #[test]
+    fn test_last_pair_is_checked() {
+        assert_eq!(violations(&[1, 2, 3, 4, 3]), [(3, Rule::DirectionChange)]);
+        assert_eq!(violations(&[1, 2, 3, 4, 4]), [(3, Rule::ZeroStep)]);
+        assert_eq!(violations(&[1, 2, 3, 4, 8]), [(3, Rule::StepTooLarge)]);
+    }
+
+    #[test]
+    fn test_policy_rules() {
+        let policy = Policy {
+            min_step: 2,
+            max_step: 4,
+            monotonicity: Monotonicity::Increasing,
+            max_removals: 0,
+        };
+        let rules: Vec<(usize, Rule)> = policy
+            .check(&[1, 3, 4, 4, 9, 7])
+            .violations
+            .iter()
+            .map(|v| (v.index, v.rule))
+            .collect();
+        assert_eq!(
+            rules,
+            [
+                (1, Rule::StepTooSmall),
+                (2, Rule::ZeroStep),
+                (3, Rule::StepTooLarge),
+                (4, Rule::DirectionChange),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_direction() {
+        // a tie goes the way of the first step
+        assert_eq!(violations(&[5, 4, 5]), [(1, Rule::DirectionChange)]);
+        assert_eq!(
+            violations(&[4, 4, 5, 4]),
+            [(0, Rule::ZeroStep), (2, Rule::DirectionChange)]
+        );
+        assert_eq!(
+            violations(&[1, 9, 8]),
+            [(0, Rule::StepTooLarge), (1, Rule::DirectionChange)]
+        );
+        assert_eq!(
+            violations(&[5, 4, 3, 9]),
+            [(2, Rule::DirectionChange), (2, Rule::StepTooLarge)]
+        );
+        assert!(Policy::default().check(&[3]).is_safe());
+        assert!(Policy::default().check(&[]).is_safe());
+    }
+
+    #[test]
+    fn test_extreme_levels() {
+        assert_eq!(violations(&[i32::MAX, i32::MIN]), [(0, Rule::StepTooLarge)]);
+        assert_eq!(
+            violations(&[i32::MIN, i32::MAX, i32::MAX - 1]),
+            [(0, Rule::StepTooLarge), (1, Rule::DirectionChange)]
+        );
+        assert_eq!(violations(&[i32::MAX - 2, i32::MAX]), []);
+    }
+}
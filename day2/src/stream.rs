@@ -0,0 +1,260 @@
+use crate::{parse_report, Policy};
+use anyhow::{Context, Result};
+use common::ParseError;
+use std::{
+    io::BufRead,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{self, Receiver, SyncSender},
+        Mutex,
+    },
+    thread,
+};
+
+/// How `stream_tally` splits up the work.
+#[derive(Debug, Clone)]
+pub struct StreamOptions {
+    /// Threads parsing and checking reports, besides the one reading.
+    pub threads: usize,
+    /// Lines handed to a thread at a time.
+    pub batch_lines: usize,
+}
+
+impl Default for StreamOptions {
+    /// A thread per core, in batches large enough that handing them out is
+    /// cheap next to checking them.
+    fn default() -> Self {
+        StreamOptions {
+            threads: thread::available_parallelism().map_or(1, |n| n.get()),
+            batch_lines: 4096,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Tally {
+    pub safe: usize,
+    pub total: usize,
+}
+
+impl Tally {
+    pub fn unsafe_count(&self) -> usize {
+        self.total - self.safe
+    }
+}
+
+/// Lines of input starting at a zero-based line number.
+type Batch = (usize, String);
+
+/// Counts the safe reports in `reader` under each of `policies`, without
+/// holding more than a few batches of input in memory.
+///
+/// The calling thread reads batches of lines and hands them to worker
+/// threads through a bounded channel, so reading waits for the workers
+/// rather than running ahead of them. Each worker parses and checks its
+/// batches and keeps its own tallies, which are added up at the end. A
+/// malformed line is reported as the first one in the input, the same as
+/// `Day2::parse` would report it, however the batches were shared out.
+/// Fails before reading anything if one of `policies` isn't valid.
+pub fn stream_tally(
+    mut reader: impl BufRead,
+    policies: &[Policy],
+    options: &StreamOptions,
+) -> Result<Vec<Tally>> {
+    for policy in policies {
+        policy.validate()?;
+    }
+    let threads = options.threads.max(1);
+    let (sender, receiver) = mpsc::sync_channel::<Batch>(threads * 2);
+    let receiver = Mutex::new(receiver);
+    // the line number of the first malformed line found so far
+    let first_bad = AtomicUsize::new(usize::MAX);
+
+    thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads)
+            .map(|_| scope.spawn(|| check_batches(&receiver, policies, &first_bad)))
+            .collect();
+        let read = read_batches(&mut reader, options.batch_lines.max(1), sender, &first_bad);
+
+        let mut tallies = vec![Tally::default(); policies.len()];
+        let mut first_error: Option<ParseError> = None;
+        for worker in workers {
+            let (worker_tallies, error) = worker.join().expect("report worker panicked");
+            for (tally, worker_tally) in tallies.iter_mut().zip(worker_tallies) {
+                tally.safe += worker_tally.safe;
+                tally.total += worker_tally.total;
+            }
+            if let Some(error) = error {
+                if first_error.as_ref().is_none_or(|e| error.line() < e.line()) {
+                    first_error = Some(error);
+                }
+            }
+        }
+
+        read?;
+        match first_error {
+            Some(error) => Err(error.into()),
+            None => Ok(tallies),
+        }
+    })
+}
+
+/// Sends the input out in batches until it runs out or a worker finds a
+/// malformed line. Dropping `sender` at the end tells the workers to stop.
+fn read_batches(
+    reader: &mut impl BufRead,
+    batch_lines: usize,
+    sender: SyncSender<Batch>,
+    first_bad: &AtomicUsize,
+) -> Result<()> {
+    let mut start = 0;
+    while first_bad.load(Ordering::Relaxed) == usize::MAX {
+        let mut batch = String::new();
+        let mut lines = 0;
+        while lines < batch_lines {
+            if reader
+                .read_line(&mut batch)
+                .context("Failed to read input")?
+                == 0
+            {
+                break;
+            }
+            lines += 1;
+        }
+        if lines == 0 || sender.send((start, batch)).is_err() {
+            break;
+        }
+        start += lines;
+    }
+    Ok(())
+}
+
+/// Checks batches until the channel closes. Batches starting after a
+/// malformed line another worker found are skipped, as that line will be
+/// the one reported.
+fn check_batches(
+    receiver: &Mutex<Receiver<Batch>>,
+    policies: &[Policy],
+    first_bad: &AtomicUsize,
+) -> (Vec<Tally>, Option<ParseError>) {
+    let mut tallies = vec![Tally::default(); policies.len()];
+    let mut error = None;
+    let mut levels = Vec::new();
+
+    loop {
+        let received = receiver.lock().expect("report channel poisoned").recv();
+        let Ok((start, batch)) = received else {
+            break;
+        };
+        if start > first_bad.load(Ordering::Relaxed) {
+            continue;
+        }
+
+        for (idx, line) in batch.lines().enumerate() {
+            if let Err(e) = parse_report(line, &mut levels) {
+                first_bad.fetch_min(start + idx, Ordering::Relaxed);
+                error = Some(e.offset_lines(start + idx));
+                break;
+            }
+            for (tally, policy) in tallies.iter_mut().zip(policies) {
+                tally.total += 1;
+                if policy.is_safe(&levels) {
+                    tally.safe += 1;
+                }
+            }
+        }
+    }
+    (tallies, error)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{parse_data, summarise};
+    use common::Lcg;
+    use std::io::Cursor;
+
+    /// Reports of 5 to 8 levels that mostly step by 1 to 3 in one direction,
+    /// with an occasional bad step, so all verdicts turn up.
+    fn generate(reports: usize, seed: u64) -> String {
+        let mut rng = Lcg::new(seed);
+
+        let mut input = String::new();
+        for _ in 0..reports {
+            let direction = if rng.below(2) == 0 { 1 } else { -1 };
+            let mut level = 50;
+            let len = 5 + rng.below(4);
+            for idx in 0..len {
+                if idx > 0 {
+                    input.push(' ');
+                    level += match rng.below(10) {
+                        0 => rng.below(7) as i32 - 3,
+                        _ => direction * (1 + rng.below(3) as i32),
+                    };
+                }
+                input.push_str(&level.to_string());
+            }
+            input.push('\n');
+        }
+        input
+    }
+
+    #[test]
+    fn test_matches_summarise() {
+        let input = generate(5000, 2024);
+        let policies = [Policy::default(), Policy::dampened()];
+        let reports = parse_data(&input).unwrap();
+        let expected: Vec<Tally> = policies
+            .iter()
+            .map(|policy| {
+                let (safe, unsafe_count) = summarise(&reports, policy).unwrap();
+                Tally {
+                    safe: safe as usize,
+                    total: (safe + unsafe_count) as usize,
+                }
+            })
+            .collect();
+
+        for (threads, batch_lines) in [(1, 1), (3, 7), (4, 1000), (2, 10_000)] {
+            let options = StreamOptions {
+                threads,
+                batch_lines,
+            };
+            let tallies = stream_tally(Cursor::new(&input), &policies, &options).unwrap();
+            assert_eq!(tallies, expected, "{:?}", options);
+        }
+    }
+
+    #[test]
+    fn test_first_error_wins() {
+        let mut input = generate(2000, 7);
+        input.push_str("1 2 x\n");
+        input.push_str(&generate(2000, 8));
+        input.push_str("\n1 2 3\n");
+        let expected = parse_data(&input).unwrap_err();
+
+        for threads in [1, 4] {
+            let options = StreamOptions {
+                threads,
+                batch_lines: 16,
+            };
+            let err =
+                stream_tally(Cursor::new(&input), &[Policy::default()], &options).unwrap_err();
+            assert_eq!(err.downcast_ref::<ParseError>(), Some(&expected));
+        }
+    }
+
+    #[test]
+    fn test_invalid_policy() {
+        let policy = Policy {
+            min_step: -1,
+            ..Policy::default()
+        };
+        let err =
+            stream_tally(Cursor::new("1 2 3\n"), &[policy], &StreamOptions::default()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "The minimum step can't be negative, got -1"
+        );
+    }
+}
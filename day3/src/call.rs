@@ -0,0 +1,112 @@
+use crate::token::{
+    iter_closing_brace, iter_comma, iter_literal, parse_digits, Grammar, Token, TokenType,
+};
+use anyhow::{anyhow, Result};
+
+/// An instruction registered with an `InstructionSet` rather than built into
+/// the lexer, written as its name and comma separated operands, such as
+/// `add(1,2)`.
+pub struct Call {
+    pub name: String,
+    pub operands: Vec<i32>,
+}
+
+impl Token for Call {
+    fn get_type(&self) -> TokenType {
+        TokenType::Call(self.name.clone())
+    }
+
+    fn get_text(&self) -> String {
+        let operands: Vec<String> = self.operands.iter().map(i32::to_string).collect();
+        format!("{}({})", self.name, operands.join(", "))
+    }
+
+    fn operands(&self) -> Vec<i64> {
+        self.operands
+            .iter()
+            .map(|&operand| operand as i64)
+            .collect()
+    }
+
+    fn from_token_string(token_string: &str) -> Result<Call> {
+        Call::from_token_string(token_string)
+    }
+}
+
+impl Call {
+    /// Reads a call to whatever name comes before the opening parenthesis.
+    pub fn from_token_string(token_string: &str) -> Result<Call> {
+        let name = match token_string.find('(') {
+            Some(end) if end > 0 => &token_string[..end],
+            _ => return Err(anyhow!("No instruction name found")),
+        };
+        Call::lex(token_string.as_bytes(), name, Grammar::default()).map(|(call, _)| call)
+    }
+
+    /// Reads a call to `name` from the start of `bytes`, returning it and its
+    /// length.
+    pub fn lex(bytes: &[u8], name: &str, grammar: Grammar) -> Result<(Call, usize)> {
+        let mut idx = iter_literal(bytes, 0, name.as_bytes())?;
+        idx = iter_literal(bytes, idx, b"(")?;
+        idx = grammar.skip_whitespace(bytes, idx);
+
+        let mut operands = Vec::new();
+        if bytes.get(idx) != Some(&b')') {
+            loop {
+                let start_ind = idx;
+                idx = grammar.iter_operand(bytes, idx)?;
+                operands.push(parse_digits(bytes, start_ind, idx)?);
+                idx = grammar.skip_whitespace(bytes, idx);
+                if bytes.get(idx) != Some(&b',') {
+                    break;
+                }
+                idx = iter_comma(bytes, idx)?;
+                idx = grammar.skip_whitespace(bytes, idx);
+            }
+        }
+        idx = iter_closing_brace(bytes, idx)?;
+
+        let call = Call {
+            name: name.to_string(),
+            operands,
+        };
+        Ok((call, idx))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_call_parse() {
+        let call = Call::from_token_string("add(1,22,333)").unwrap();
+        assert_eq!(
+            (call.name.as_str(), call.operands),
+            ("add", vec![1, 22, 333])
+        );
+        assert!(Call::from_token_string("nop()")
+            .unwrap()
+            .operands
+            .is_empty());
+
+        for input in ["add(1,)", "add(,1)", "add(1", "(1)", "add", "add[1]"] {
+            assert!(Call::from_token_string(input).is_err(), "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn test_call_grammar() {
+        let lex = |input: &str, grammar| {
+            Call::lex(input.as_bytes(), "add", grammar).map(|(call, len)| (call.operands, len))
+        };
+
+        assert_eq!(lex("add(1,2)x", Grammar::Strict).unwrap(), (vec![1, 2], 8));
+        for input in ["add( 1)", "add(1 ,2)", "add(1234)"] {
+            assert!(lex(input, Grammar::Strict).is_err(), "{:?}", input);
+        }
+        assert_eq!(lex("add( 1 , 2 )", Grammar::Lenient).unwrap().0, [1, 2]);
+        assert_eq!(lex("add( )", Grammar::Lenient).unwrap().0, []);
+        assert!(lex("add (1)", Grammar::Lenient).is_err());
+    }
+}
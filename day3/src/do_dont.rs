@@ -1,19 +1,20 @@
-use crate::token::{iter_closing_brace, iter_comma, iter_digit, iter_whitespace, Token, TokenType};
-use anyhow::{anyhow, Result};
+use crate::token::{iter_closing_brace, iter_literal, Grammar, Token, TokenType};
+use anyhow::Result;
 
 pub struct Do {}
 pub struct Dont {}
 
 impl Do {
     pub fn from_token_string(token_string: &str) -> Result<Do> {
-        let mut idx = 3; // do(
-        if token_string.len() > idx && token_string[0..idx].as_bytes() != "do(".as_bytes() {
-            Err(anyhow!("No do found"))?;
-        }
-        let token_string = token_string.as_bytes();
-        idx = iter_whitespace(token_string, idx);
-        idx = iter_closing_brace(token_string, idx)?;
-        Ok(Do {})
+        Do::lex(token_string.as_bytes(), Grammar::default()).map(|(do_, _)| do_)
+    }
+
+    /// Reads a `do()` from the start of `bytes`, returning it and its length.
+    pub fn lex(bytes: &[u8], grammar: Grammar) -> Result<(Do, usize)> {
+        let mut idx = iter_literal(bytes, 0, b"do(")?;
+        idx = grammar.skip_whitespace(bytes, idx);
+        idx = iter_closing_brace(bytes, idx)?;
+        Ok((Do {}, idx))
     }
 }
 
@@ -23,11 +24,11 @@ impl Token for Do {
     }
 
     fn get_text(&self) -> String {
-        format!("do()")
+        "do()".to_string()
     }
 
-    fn eval(&self) -> i32 {
-        1
+    fn operands(&self) -> Vec<i64> {
+        Vec::new()
     }
 
     fn from_token_string(token_string: &str) -> Result<Do> {
@@ -36,15 +37,16 @@ impl Token for Do {
 }
 impl Dont {
     pub fn from_token_string(token_string: &str) -> Result<Dont> {
-        let mut idx = 6; // don't(
-        if token_string.len() > idx && token_string[0..idx].as_bytes() != "don't(".as_bytes() {
-            Err(anyhow!("No do found"))?;
-        }
+        Dont::lex(token_string.as_bytes(), Grammar::default()).map(|(dont, _)| dont)
+    }
 
-        let token_string = token_string.as_bytes();
-        idx = iter_whitespace(token_string, idx);
-        iter_closing_brace(token_string, idx)?;
-        Ok(Dont {})
+    /// Reads a `don't()` from the start of `bytes`, returning it and its
+    /// length.
+    pub fn lex(bytes: &[u8], grammar: Grammar) -> Result<(Dont, usize)> {
+        let mut idx = iter_literal(bytes, 0, b"don't(")?;
+        idx = grammar.skip_whitespace(bytes, idx);
+        idx = iter_closing_brace(bytes, idx)?;
+        Ok((Dont {}, idx))
     }
 }
 
@@ -54,11 +56,11 @@ impl Token for Dont {
     }
 
     fn get_text(&self) -> String {
-        format!("do()")
+        "don't()".to_string()
     }
 
-    fn eval(&self) -> i32 {
-        0
+    fn operands(&self) -> Vec<i64> {
+        Vec::new()
     }
 
     fn from_token_string(token_string: &str) -> Result<Dont> {
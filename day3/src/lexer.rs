@@ -0,0 +1,163 @@
+use crate::call::Call;
+use crate::do_dont::{Do, Dont};
+use crate::mul::Mul;
+use crate::token::{Grammar, Token, TokenType};
+use crate::vm::InstructionSet;
+use std::ops::Range;
+
+/// A token and the bytes of the input it was read from.
+pub struct Lexeme {
+    pub span: Range<usize>,
+    pub token: Box<dyn Token>,
+}
+
+/// Finds the instructions in corrupted memory in one pass, skipping
+/// everything else.
+///
+/// Every built-in instruction starts with an ASCII letter, and an ASCII byte
+/// is never part of a multi-byte UTF-8 character, while other instructions
+/// are matched on the whole of their name. So the lexer works on bytes and
+/// every span it produces starts and ends on a character boundary.
+pub struct Lexer<'a> {
+    input: &'a [u8],
+    pos: usize,
+    grammar: Grammar,
+    /// Instructions recognised besides the built-in ones.
+    names: Vec<&'static str>,
+}
+
+impl<'a> Lexer<'a> {
+    /// A lexer for the strict grammar.
+    pub fn new(input: &'a str) -> Lexer<'a> {
+        Lexer::with_grammar(input, Grammar::default())
+    }
+
+    pub fn with_grammar(input: &'a str, grammar: Grammar) -> Lexer<'a> {
+        Lexer {
+            input: input.as_bytes(),
+            pos: 0,
+            grammar,
+            names: Vec::new(),
+        }
+    }
+
+    /// Also recognises the instructions registered in `set`, other than the
+    /// built-in ones, written as their name and operands like `add(1,2)`.
+    pub fn recognising(mut self, set: &InstructionSet) -> Lexer<'a> {
+        self.names = set
+            .names()
+            .filter(|name| !name.is_empty() && !TokenType::BUILT_IN.contains(name))
+            .collect();
+        self
+    }
+
+    /// The instruction starting exactly at `start`, if there is one, and its
+    /// length.
+    fn lex_at(&self, start: usize) -> Option<(Box<dyn Token>, usize)> {
+        let bytes = &self.input[start..];
+        let built_in = match bytes[0] {
+            b'm' => Mul::lex(bytes, self.grammar)
+                .ok()
+                .map(|(mul, len)| (Box::new(mul) as Box<dyn Token>, len)),
+            b'd' => match Dont::lex(bytes, self.grammar) {
+                Ok((dont, len)) => Some((Box::new(dont) as Box<dyn Token>, len)),
+                Err(_) => Do::lex(bytes, self.grammar)
+                    .ok()
+                    .map(|(do_, len)| (Box::new(do_) as _, len)),
+            },
+            _ => None,
+        };
+        built_in.or_else(|| {
+            self.names.iter().find_map(|name| {
+                Call::lex(bytes, name, self.grammar)
+                    .ok()
+                    .map(|(call, len)| (Box::new(call) as Box<dyn Token>, len))
+            })
+        })
+    }
+}
+
+impl Iterator for Lexer<'_> {
+    type Item = Lexeme;
+
+    fn next(&mut self) -> Option<Lexeme> {
+        while self.pos < self.input.len() {
+            let start = self.pos;
+            // no instruction can start inside another, so carry on after it
+            if let Some((token, len)) = self.lex_at(start) {
+                self.pos = start + len;
+                return Some(Lexeme {
+                    span: start..start + len,
+                    token,
+                });
+            }
+            self.pos += 1;
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn spans(input: &str) -> Vec<&str> {
+        Lexer::new(input)
+            .map(|lexeme| &input[lexeme.span])
+            .collect()
+    }
+
+    #[test]
+    fn test_example() {
+        let input = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+        assert_eq!(
+            spans(input),
+            [
+                "mul(2,4)",
+                "don't()",
+                "mul(5,5)",
+                "mul(11,8)",
+                "do()",
+                "mul(8,5)"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unicode_input() {
+        let input = "é mul(2,3)ü don't()日本mul(1,1)mül(4,4)dö()";
+        assert_eq!(spans(input), ["mul(2,3)", "don't()", "mul(1,1)"]);
+        let texts: Vec<String> = Lexer::new(input).map(|l| l.token.get_text()).collect();
+        assert_eq!(texts, ["mul(2, 3)", "don't()", "mul(1, 1)"]);
+    }
+
+    #[test]
+    fn test_grammar() {
+        // the tricky cases from the puzzle text, only the last is an instruction
+        let input = "mul(4*mul(6,9!?(12,34)mul ( 2 , 4 )mul[3,7]mul(1,2)";
+        assert_eq!(spans(input), ["mul(1,2)"]);
+
+        let input = "mul( 2 , 4 )do( )mul(1234,5)don't(\t)mul(7,8)";
+        assert_eq!(spans(input), ["mul(7,8)"]);
+        let lenient: Vec<&str> = Lexer::with_grammar(input, Grammar::Lenient)
+            .map(|lexeme| &input[lexeme.span])
+            .collect();
+        assert_eq!(
+            lenient,
+            [
+                "mul( 2 , 4 )",
+                "do( )",
+                "mul(1234,5)",
+                "don't(\t)",
+                "mul(7,8)"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_truncated_input() {
+        for input in ["mul(", "mul(1,", "don't(", "do", "m", "d"] {
+            assert!(spans(input).is_empty(), "{:?}", input);
+        }
+    }
+}
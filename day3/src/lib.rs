@@ -0,0 +1,38 @@
+pub mod call;
+pub mod do_dont;
+pub mod lexer;
+pub mod mul;
+pub mod token;
+pub mod vm;
+use anyhow::Result;
+use common::{Answer, Solution};
+use lexer::{Lexeme, Lexer};
+use vm::{InstructionSet, Machine, Program};
+
+pub use token::Grammar;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Parsed = Program;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        let set = InstructionSet::standard();
+        let lexemes: Vec<Lexeme> = Lexer::new(input).recognising(&set).collect();
+        Program::compile(&lexemes, &set)
+    }
+
+    fn part1(program: &Self::Parsed) -> Result<Answer> {
+        // the first part ignores do() and don't()
+        let mut set = InstructionSet::standard();
+        set.register("do", 0, |_, _| {});
+        set.register("don't", 0, |_, _| {});
+        Ok(Machine::default().run(program, &set).into())
+    }
+
+    fn part2(program: &Self::Parsed) -> Result<Answer> {
+        Ok(Machine::default()
+            .run(program, &InstructionSet::standard())
+            .into())
+    }
+}
@@ -1,7 +1,7 @@
 use crate::token::{
-    self, iter_closing_brace, iter_comma, iter_digit, iter_whitespace, Token, TokenType,
+    iter_closing_brace, iter_comma, iter_literal, parse_digits, Grammar, Token, TokenType,
 };
-use anyhow::{anyhow, Result};
+use anyhow::Result;
 
 pub struct Mul {
     pub x: i32,
@@ -17,8 +17,8 @@ impl Token for Mul {
         format!("mul({}, {})", self.x, self.y)
     }
 
-    fn eval(&self) -> i32 {
-        self.x * self.y
+    fn operands(&self) -> Vec<i64> {
+        vec![self.x as i64, self.y as i64]
     }
 
     fn from_token_string(token_string: &str) -> Result<Mul> {
@@ -32,39 +32,29 @@ impl Mul {
     }
 
     pub fn from_token_string(token_string: &str) -> Result<Mul> {
-        let mut idx = 4; // mul(
-        if token_string.len() > idx && token_string[0..idx].as_bytes() != "mul(".as_bytes() {
-            Err(anyhow!("No mul found"))?;
-        }
-
-        let token_string = token_string.as_bytes();
-        let mut x_start_ind = -1;
-        let mut x_end_ind = -1;
-        let mut y_start_ind = -1;
-        let mut y_end_ind = -1;
+        Mul::lex(token_string.as_bytes(), Grammar::default()).map(|(mul, _)| mul)
+    }
 
-        idx = iter_whitespace(token_string, idx);
-        x_start_ind = idx.clone() as i32;
-        idx = iter_digit(token_string, idx)?;
-        x_end_ind = idx.clone() as i32;
-        idx = iter_whitespace(token_string, idx);
-        idx = iter_comma(token_string, idx)?;
-        idx = iter_whitespace(token_string, idx);
-        y_start_ind = idx.clone() as i32;
-        idx = iter_digit(token_string, idx)?;
-        y_end_ind = idx.clone() as i32;
-        idx = iter_whitespace(token_string, idx);
-        idx = iter_closing_brace(token_string, idx)?;
+    /// Reads a `mul(x,y)` from the start of `bytes`, returning it and its
+    /// length.
+    pub fn lex(bytes: &[u8], grammar: Grammar) -> Result<(Mul, usize)> {
+        let mut idx = iter_literal(bytes, 0, b"mul(")?;
+        idx = grammar.skip_whitespace(bytes, idx);
+        let x_start_ind = idx;
+        idx = grammar.iter_operand(bytes, idx)?;
+        let x_end_ind = idx;
+        idx = grammar.skip_whitespace(bytes, idx);
+        idx = iter_comma(bytes, idx)?;
+        idx = grammar.skip_whitespace(bytes, idx);
+        let y_start_ind = idx;
+        idx = grammar.iter_operand(bytes, idx)?;
+        let y_end_ind = idx;
+        idx = grammar.skip_whitespace(bytes, idx);
+        idx = iter_closing_brace(bytes, idx)?;
 
-        let x = String::from_utf8(token_string[x_start_ind as usize..x_end_ind as usize].to_vec())
-            .unwrap()
-            .parse::<i32>()
-            .unwrap();
-        let y = String::from_utf8(token_string[y_start_ind as usize..y_end_ind as usize].to_vec())
-            .unwrap()
-            .parse::<i32>()
-            .unwrap();
-        Ok(Mul { x, y })
+        let x = parse_digits(bytes, x_start_ind, x_end_ind)?;
+        let y = parse_digits(bytes, y_start_ind, y_end_ind)?;
+        Ok((Mul::new(x, y), idx))
     }
 }
 
@@ -78,4 +68,40 @@ mod test {
         assert_eq!(mul.x, 2);
         assert_eq!(mul.y, 4);
     }
+
+    #[test]
+    fn test_mul_parse_rejects() {
+        for input in [
+            "mul(2,4",
+            "mul(2,4]",
+            "mul[2,4)",
+            "mul",
+            "",
+            "mul(99999999999,1)",
+        ] {
+            assert!(Mul::from_token_string(input).is_err(), "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn test_mul_grammar() {
+        let lex = |input: &str, grammar| {
+            Mul::lex(input.as_bytes(), grammar).map(|(mul, _)| (mul.x, mul.y))
+        };
+
+        for input in ["mul(1,2)", "mul(12,34)", "mul(123,456)"] {
+            assert!(lex(input, Grammar::Strict).is_ok(), "{:?}", input);
+        }
+        for input in ["mul( 2 , 4 )", "mul(2, 4)", "mul(1234,5)", "mul(5,1234)"] {
+            assert!(lex(input, Grammar::Strict).is_err(), "{:?}", input);
+        }
+
+        assert_eq!(lex("mul( 2 , 4 )", Grammar::Lenient).unwrap(), (2, 4));
+        assert_eq!(lex("mul(1234,5)", Grammar::Lenient).unwrap(), (1234, 5));
+        for grammar in [Grammar::Strict, Grammar::Lenient] {
+            for input in ["mul(4*", "mul[3,7]", "mul(6,9!", "mul ( 2,4)"] {
+                assert!(lex(input, grammar).is_err(), "{:?} {:?}", input, grammar);
+            }
+        }
+    }
 }
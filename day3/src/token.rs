@@ -1,9 +1,11 @@
 use anyhow::{anyhow, Result};
 
+/// An instruction as read from memory. What it does when run is up to the
+/// `InstructionSet` it is compiled against.
 pub trait Token {
     fn get_type(&self) -> TokenType;
     fn get_text(&self) -> String;
-    fn eval(&self) -> i32;
+    fn operands(&self) -> Vec<i64>;
     fn from_token_string(token_string: &str) -> Result<Self>
     where
         Self: Sized;
@@ -13,31 +15,100 @@ pub enum TokenType {
     Mul,
     Do,
     Dont,
+    /// An instruction the lexer was told about by an `InstructionSet`.
+    Call(String),
 }
 
-pub fn iter_digit(token_string: &[u8], idx: usize) -> Result<usize> {
-    if idx > token_string.len() - 1 {
-        Err(anyhow!("String index out of bounds"))?
+impl TokenType {
+    /// Names of the instructions the lexer always recognises.
+    pub const BUILT_IN: [&'static str; 3] = ["mul", "do", "don't"];
+
+    /// The name the instruction is registered under.
+    pub fn name(&self) -> &str {
+        match self {
+            TokenType::Mul => "mul",
+            TokenType::Do => "do",
+            TokenType::Dont => "don't",
+            TokenType::Call(name) => name,
+        }
     }
+}
+
+/// How closely instructions have to follow the puzzle's spelling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Grammar {
+    /// Exactly as the puzzle describes: no whitespace inside an instruction
+    /// and `mul` operands of 1 to 3 digits.
+    #[default]
+    Strict,
+    /// Whitespace anywhere inside the parentheses, though not between the
+    /// name and the opening parenthesis, and operands of any length.
+    Lenient,
+}
 
-    let orig = idx.clone();
+impl Grammar {
+    /// Longest operand the strict grammar allows.
+    const MAX_DIGITS: usize = 3;
+
+    /// Skips whitespace, if the grammar allows it at `idx`.
+    pub fn skip_whitespace(self, token_string: &[u8], idx: usize) -> usize {
+        match self {
+            Grammar::Strict => idx,
+            Grammar::Lenient => iter_whitespace(token_string, idx),
+        }
+    }
+
+    /// Like `iter_digit`, but rejecting operands too long for the grammar.
+    pub fn iter_operand(self, token_string: &[u8], idx: usize) -> Result<usize> {
+        let end = iter_digit(token_string, idx)?;
+        if self == Grammar::Strict && end - idx > Grammar::MAX_DIGITS {
+            return Err(anyhow!(
+                "Operand at index {} is longer than {} digits",
+                idx,
+                Grammar::MAX_DIGITS
+            ));
+        }
+        Ok(end)
+    }
+}
+
+/// Checks that `token_string` has `literal` at `idx` and returns the index
+/// after it.
+pub fn iter_literal(token_string: &[u8], idx: usize, literal: &[u8]) -> Result<usize> {
+    match token_string.get(idx..) {
+        Some(rest) if rest.starts_with(literal) => Ok(idx + literal.len()),
+        _ => Err(anyhow!(
+            "No {:?} found for index {}",
+            String::from_utf8_lossy(literal),
+            idx
+        )),
+    }
+}
+
+pub fn iter_digit(token_string: &[u8], idx: usize) -> Result<usize> {
+    let orig = idx;
     let mut idx = idx;
     while idx < token_string.len() && token_string[idx].is_ascii_digit() {
         idx += 1;
     }
     if orig == idx {
-        Err(anyhow!(
-            "No digit found for index {} in {:?}",
-            orig,
-            String::from_utf8(token_string[idx..].to_vec()).unwrap()
-        ))
+        Err(anyhow!("No digit found for index {}", orig))
     } else {
         Ok(idx)
     }
 }
 
+/// Parses the digits in `token_string[start..end]`, as found by
+/// `iter_digit`.
+pub fn parse_digits(token_string: &[u8], start: usize, end: usize) -> Result<i32> {
+    // digits are ASCII, so this never splits a character
+    std::str::from_utf8(&token_string[start..end])?
+        .parse()
+        .map_err(|e| anyhow!("Invalid number at index {}: {}", start, e))
+}
+
 pub fn iter_comma(token_string: &[u8], idx: usize) -> Result<usize> {
-    if idx > token_string.len() - 1 || token_string[idx] != b',' {
+    if token_string.get(idx) != Some(&b',') {
         Err(anyhow!("No comma found for index {}", idx))
     } else {
         Ok(idx + 1)
@@ -45,7 +116,7 @@ pub fn iter_comma(token_string: &[u8], idx: usize) -> Result<usize> {
 }
 
 pub fn iter_closing_brace(token_string: &[u8], idx: usize) -> Result<usize> {
-    if idx > token_string.len() - 1 || token_string[idx] != b')' {
+    if token_string.get(idx) != Some(&b')') {
         Err(anyhow!("No closing brace found for index {}", idx))
     } else {
         Ok(idx + 1)
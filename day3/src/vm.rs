@@ -0,0 +1,253 @@
+use crate::lexer::Lexeme;
+use anyhow::{anyhow, Result};
+use std::ops::Range;
+
+/// What an instruction does to the machine, given its operands.
+pub type Operation = fn(&mut Machine, &[i64]);
+
+/// Index of an instruction in an `InstructionSet`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Opcode(usize);
+
+struct Definition {
+    name: &'static str,
+    arity: usize,
+    operation: Operation,
+}
+
+/// The instructions a program can be compiled to, looked up by name.
+#[derive(Default)]
+pub struct InstructionSet {
+    definitions: Vec<Definition>,
+}
+
+impl InstructionSet {
+    /// The puzzle's instructions: `mul` adds its product to the accumulator
+    /// unless a `don't()` has disabled it, and `do()` enables it again.
+    pub fn standard() -> InstructionSet {
+        let mut set = InstructionSet::default();
+        set.register("mul", 2, |machine, operands| {
+            if machine.enabled {
+                machine.accumulator += operands[0] * operands[1];
+            }
+        });
+        set.register("do", 0, |machine, _| machine.enabled = true);
+        set.register("don't", 0, |machine, _| machine.enabled = false);
+        set
+    }
+
+    /// Adds an instruction, or replaces the one already registered under
+    /// `name`. A replaced instruction keeps its opcode, so programs compiled
+    /// against one set can be run with a variation of it. A new instruction
+    /// is read from input written like `name(1,2)` by a lexer that is
+    /// `recognising` the set.
+    pub fn register(&mut self, name: &'static str, arity: usize, operation: Operation) -> Opcode {
+        let definition = Definition {
+            name,
+            arity,
+            operation,
+        };
+        match self.opcode(name) {
+            Some(opcode) => {
+                self.definitions[opcode.0] = definition;
+                opcode
+            }
+            None => {
+                self.definitions.push(definition);
+                Opcode(self.definitions.len() - 1)
+            }
+        }
+    }
+
+    pub fn opcode(&self, name: &str) -> Option<Opcode> {
+        self.definitions
+            .iter()
+            .position(|definition| definition.name == name)
+            .map(Opcode)
+    }
+
+    pub fn name(&self, opcode: Opcode) -> &'static str {
+        self.definitions[opcode.0].name
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.definitions.iter().map(|definition| definition.name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Instruction {
+    pub opcode: Opcode,
+    pub operands: Vec<i64>,
+    /// Where in the input the instruction was read from.
+    pub span: Range<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Program {
+    pub instructions: Vec<Instruction>,
+}
+
+impl Program {
+    /// Compiles lexed tokens to instructions from `set`, failing on a token
+    /// the set has no instruction for or one with the wrong operand count.
+    pub fn compile(lexemes: &[Lexeme], set: &InstructionSet) -> Result<Program> {
+        let instructions = lexemes
+            .iter()
+            .map(|Lexeme { span, token }| {
+                let token_type = token.get_type();
+                let name = token_type.name();
+                let opcode = set
+                    .opcode(name)
+                    .ok_or_else(|| anyhow!("No instruction `{}` at bytes {:?}", name, span))?;
+                let operands = token.operands();
+                let arity = set.definitions[opcode.0].arity;
+                if operands.len() != arity {
+                    return Err(anyhow!(
+                        "`{}` at bytes {:?} has {} operands, expected {}",
+                        name,
+                        span,
+                        operands.len(),
+                        arity
+                    ));
+                }
+                Ok(Instruction {
+                    opcode,
+                    operands,
+                    span: span.clone(),
+                })
+            })
+            .collect::<Result<_>>()?;
+        Ok(Program { instructions })
+    }
+}
+
+/// The interpreter's state while running a program.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Machine {
+    pub enabled: bool,
+    pub accumulator: i64,
+    /// The next instruction to run. It is moved on before an instruction
+    /// runs, so an instruction can set it to jump.
+    pub ip: usize,
+}
+
+impl Default for Machine {
+    fn default() -> Self {
+        Machine {
+            enabled: true,
+            accumulator: 0,
+            ip: 0,
+        }
+    }
+}
+
+impl Machine {
+    /// Runs `program` until the instruction pointer leaves it and returns
+    /// the accumulator.
+    pub fn run(&mut self, program: &Program, set: &InstructionSet) -> i64 {
+        while let Some(instruction) = program.instructions.get(self.ip) {
+            self.ip += 1;
+            (set.definitions[instruction.opcode.0].operation)(self, &instruction.operands);
+        }
+        self.accumulator
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::lexer::Lexer;
+
+    fn compile(input: &str, set: &InstructionSet) -> Program {
+        let lexemes: Vec<Lexeme> = Lexer::new(input).collect();
+        Program::compile(&lexemes, set).unwrap()
+    }
+
+    #[test]
+    fn test_run() {
+        let set = InstructionSet::standard();
+        let program = compile(
+            "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))",
+            &set,
+        );
+        let names: Vec<&str> = program
+            .instructions
+            .iter()
+            .map(|instruction| set.name(instruction.opcode))
+            .collect();
+        assert_eq!(names, ["mul", "don't", "mul", "mul", "do", "mul"]);
+
+        let mut machine = Machine::default();
+        assert_eq!(machine.run(&program, &set), 48);
+        assert_eq!(machine.ip, 6);
+        assert!(machine.enabled);
+    }
+
+    #[test]
+    fn test_register() {
+        let mut set = InstructionSet::standard();
+        let program = compile("mul(2,3)don't()mul(4,5)do()mul(1,1)", &set);
+
+        // replacing an instruction keeps its opcode, so the same program
+        // runs with the new behaviour
+        let dont = set.opcode("don't");
+        assert_eq!(set.register("don't", 0, |_, _| {}), dont.unwrap());
+        assert_eq!(Machine::default().run(&program, &set), 27);
+
+        set.register("mul", 2, |machine, operands| {
+            machine.accumulator += operands[0] + operands[1];
+        });
+        assert_eq!(Machine::default().run(&program, &set), 16);
+    }
+
+    #[test]
+    fn test_register_new_instruction() {
+        let mut set = InstructionSet::standard();
+        let add = set.register("add", 1, |machine, operands| {
+            if machine.enabled {
+                machine.accumulator += operands[0];
+            }
+        });
+        let input = "add(5)mul(2,3)don't()add(100)do()xadd(40)?add[1]";
+
+        // a lexer that hasn't been told about it skips it like any other noise
+        assert_eq!(Lexer::new(input).count(), 3);
+
+        let lexemes: Vec<Lexeme> = Lexer::new(input).recognising(&set).collect();
+        let program = Program::compile(&lexemes, &set).unwrap();
+        let names: Vec<&str> = program
+            .instructions
+            .iter()
+            .map(|instruction| set.name(instruction.opcode))
+            .collect();
+        assert_eq!(names, ["add", "mul", "don't", "add", "do", "add"]);
+        assert_eq!(program.instructions[0].opcode, add);
+        assert_eq!(program.instructions[5].span, 34..41);
+        assert_eq!(Machine::default().run(&program, &set), 51);
+
+        let lexemes: Vec<Lexeme> = Lexer::new("add(1,2)").recognising(&set).collect();
+        let err = Program::compile(&lexemes, &set).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "`add` at bytes 0..8 has 2 operands, expected 1"
+        );
+    }
+
+    #[test]
+    fn test_compile_errors() {
+        let lexemes: Vec<Lexeme> = Lexer::new("do()mul(1,2)").collect();
+
+        let mut set = InstructionSet::default();
+        set.register("do", 0, |_, _| {});
+        let err = Program::compile(&lexemes, &set).unwrap_err();
+        assert_eq!(err.to_string(), "No instruction `mul` at bytes 4..12");
+
+        set.register("mul", 3, |_, _| {});
+        let err = Program::compile(&lexemes, &set).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "`mul` at bytes 4..12 has 2 operands, expected 3"
+        );
+    }
+}
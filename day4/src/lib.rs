@@ -0,0 +1,139 @@
+use anyhow::{anyhow, Result};
+use common::{Answer, Solution};
+use grid::{Grid, Point};
+
+static XMAS: [char; 4] = ['X', 'M', 'A', 'S'];
+static MAS: [char; 3] = ['M', 'A', 'S'];
+
+fn analyze(match_seq: &[char], grid: &Grid<char>, start: Point, step: Point) -> usize {
+    let found = grid
+        .ray(start, step)
+        .take(match_seq.len())
+        .map(|p| grid[p])
+        .eq(match_seq.iter().copied());
+    usize::from(found)
+}
+
+fn count_xmas(grid: &Grid<char>) -> usize {
+    // set a search in each direction from every square
+    grid.points()
+        .map(|p| {
+            Point::ALL
+                .iter()
+                .map(|&step| analyze(&XMAS, grid, p, step))
+                .sum::<usize>()
+        })
+        .sum()
+}
+
+#[derive(PartialEq)]
+enum MasDirection {
+    UpperLeft,
+    UpperRight,
+    LowerLeft,
+    LowerRight,
+}
+
+impl TryFrom<usize> for MasDirection {
+    type Error = anyhow::Error;
+
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(MasDirection::UpperLeft),
+            1 => Ok(MasDirection::UpperRight),
+            2 => Ok(MasDirection::LowerLeft),
+            3 => Ok(MasDirection::LowerRight),
+            _ => Err(anyhow!("Invalid direction")),
+        }
+    }
+}
+
+impl MasDirection {
+    fn step(&self) -> Point {
+        match self {
+            MasDirection::UpperLeft => Point::new(-1, 1),
+            MasDirection::UpperRight => Point::new(1, 1),
+            MasDirection::LowerLeft => Point::new(-1, -1),
+            MasDirection::LowerRight => Point::new(1, -1),
+        }
+    }
+}
+
+#[derive(PartialEq)]
+struct Mas {
+    start: Point,
+    direction: MasDirection,
+}
+
+impl Mas {
+    fn with_direction(start: Point, direction: usize) -> Result<Mas> {
+        Ok(Mas {
+            start,
+            direction: MasDirection::try_from(direction)?,
+        })
+    }
+
+    fn middle(&self) -> Point {
+        self.start + self.direction.step()
+    }
+
+    fn intersects(&self, other: &Mas) -> bool {
+        self.middle() == other.middle()
+    }
+}
+
+fn find_mas(grid: &Grid<char>) -> Vec<Mas> {
+    let mut mas = Vec::new();
+    // find all MAS
+    for p in grid.points() {
+        // set a search in all diagonal directions
+        for idx in 0..4 {
+            let Ok(mas_) = Mas::with_direction(p, idx) else {
+                continue;
+            };
+            if analyze(&MAS, grid, p, mas_.direction.step()) > 0 {
+                mas.push(mas_);
+            }
+        }
+    }
+    mas
+}
+
+fn count_mas(grid: &Grid<char>) -> usize {
+    let mut mas_count = 0;
+    let mas = find_mas(grid);
+
+    for this_m in &mas {
+        for other_m in &mas {
+            if this_m == other_m {
+                continue;
+            }
+            // if considered_mas.contains(&other_m) {
+            //     continue;
+            // }
+            if this_m.intersects(other_m) {
+                mas_count += 1;
+            }
+        }
+    }
+    // account for the double counting valid MAS!
+    mas_count / 2
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Parsed = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(Grid::parse(input, Some)?)
+    }
+
+    fn part1(grid: &Self::Parsed) -> Result<Answer> {
+        Ok(count_xmas(grid).into())
+    }
+
+    fn part2(grid: &Self::Parsed) -> Result<Answer> {
+        Ok(count_mas(grid).into())
+    }
+}
@@ -0,0 +1,152 @@
+use anyhow::Result;
+use common::{parse_number, Answer, ParseError, Solution};
+
+#[derive(Clone, Debug)]
+pub struct PageOrder {
+    order: Vec<usize>,
+}
+
+impl PageOrder {
+    fn from_line(line: &str) -> Result<Self, ParseError> {
+        let order = line
+            .split(",")
+            .map(|x| parse_number(line, x))
+            .collect::<Result<Vec<usize>, _>>()?;
+        Ok(PageOrder { order })
+    }
+
+    fn middle(&self) -> usize {
+        self.order[self.order.len() / 2]
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct OrderingRule {
+    page: usize,
+    before: usize,
+}
+
+impl OrderingRule {
+    fn from_line(line: &str) -> Result<Self, ParseError> {
+        let (page, before) = line
+            .split_once("|")
+            .ok_or_else(|| ParseError::at(line, line, "expected a rule like 47|53"))?;
+
+        Ok(OrderingRule {
+            page: parse_number(line, page)?,
+            before: parse_number(line, before)?,
+        })
+    }
+
+    fn is_valid(&self, order: &[usize]) -> bool {
+        let page_pos = order.iter().position(|&x| x == self.page);
+        let before_pos = order.iter().position(|&x| x == self.before);
+
+        if page_pos.is_none() || before_pos.is_none() {
+            return true;
+        }
+        page_pos.unwrap() < before_pos.unwrap()
+    }
+}
+
+fn find_applicable_rules(rules: &[OrderingRule], order: &[usize]) -> Vec<OrderingRule> {
+    rules
+        .iter()
+        .filter(|rule| order.contains(&rule.page))
+        .cloned()
+        .collect()
+}
+
+fn apply_rules(rules: &[OrderingRule], order: &[usize]) -> Option<OrderingRule> {
+    for rule in rules {
+        if !rule.is_valid(order) {
+            return Some(rule.to_owned().clone());
+        }
+    }
+    None
+}
+
+fn parse(data: &str) -> Result<(Vec<OrderingRule>, Vec<PageOrder>), ParseError> {
+    let mut rules = Vec::new();
+    let mut orders = Vec::new();
+    for (idx, line) in data.lines().enumerate() {
+        if line.contains("|") {
+            rules.push(OrderingRule::from_line(line).map_err(|e| e.offset_lines(idx))?);
+        } else if !line.trim().is_empty() {
+            orders.push(PageOrder::from_line(line).map_err(|e| e.offset_lines(idx))?);
+        }
+    }
+    Ok((rules, orders))
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Parsed = (Vec<OrderingRule>, Vec<PageOrder>);
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(parse(input)?)
+    }
+
+    fn part1((rules, orders): &Self::Parsed) -> Result<Answer> {
+        let mut total_from_correct = 0;
+        for order in orders {
+            if apply_rules(rules, &order.order).is_none() {
+                total_from_correct += order.middle();
+            }
+        }
+
+        Ok(total_from_correct.into())
+    }
+
+    fn part2((rules, orders): &Self::Parsed) -> Result<Answer> {
+        let incorrect_orders = orders
+            .iter()
+            .filter(|order| apply_rules(rules, &order.order).is_some());
+
+        let mut middle_sum_incorrect = 0;
+
+        for incorrect in incorrect_orders {
+            let rules = find_applicable_rules(rules, &incorrect.order);
+            let mut new_order = incorrect.clone();
+
+            loop {
+                match apply_rules(&rules, &new_order.order) {
+                    Some(rule) => {
+                        let page_pos = new_order
+                            .order
+                            .iter()
+                            .position(|&x| x == rule.page)
+                            .unwrap();
+                        let before_pos = new_order
+                            .order
+                            .iter()
+                            .position(|&x| x == rule.before)
+                            .unwrap();
+                        new_order.order.swap(page_pos, before_pos);
+                    }
+                    None => {
+                        middle_sum_incorrect += new_order.middle();
+                        break;
+                    }
+                }
+            }
+        }
+
+        Ok(middle_sum_incorrect.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_error_location() {
+        let err = parse("47|53\n97|1a\n\n75,47\n").unwrap_err();
+        assert_eq!((err.line(), err.column(), err.text()), (2, 4, "1a"));
+
+        let err = parse("47|53\n\n75,,47\n").unwrap_err();
+        assert_eq!((err.line(), err.column(), err.text()), (3, 4, ""));
+    }
+}
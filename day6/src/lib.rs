@@ -0,0 +1,246 @@
+use anyhow::Result;
+use common::{Answer, ParseError, Solution};
+use grid::{Grid, Point};
+use std::fmt::Display;
+
+#[derive(Clone, PartialEq)]
+enum Direction {
+    None,
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    fn step(&self) -> Point {
+        match self {
+            Direction::Up => Point::UP,
+            Direction::Down => Point::DOWN,
+            Direction::Left => Point::LEFT,
+            Direction::Right => Point::RIGHT,
+            Direction::None => panic!("Invalid direction"),
+        }
+    }
+}
+
+#[derive(PartialEq, Clone, Debug)]
+enum SquareType {
+    Standard,
+    Obstacle,
+    Guard,
+}
+
+#[derive(Clone)]
+struct Square {
+    square_type: SquareType,
+    direction: Direction,
+    last_visit: Option<Direction>,
+    new_obstacle: bool,
+}
+
+impl Square {
+    fn visit(&mut self, direction: &Direction) -> MapState {
+        match self.square_type {
+            SquareType::Standard => match &self.last_visit {
+                Some(last_direction) => {
+                    if *last_direction == direction.clone() {
+                        return MapState::Loop;
+                    }
+                }
+                None => self.last_visit = Some(direction.clone()),
+            },
+            SquareType::Obstacle => panic!("Obstacle cannot be visited"),
+            SquareType::Guard => panic!("Guard cannot be visited"),
+        }
+        MapState::Continue
+    }
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Self {
+                square_type: SquareType::Standard,
+                direction: Direction::None,
+                last_visit: None,
+                new_obstacle: false,
+            }),
+            '#' => Some(Self {
+                square_type: SquareType::Obstacle,
+                direction: Direction::None,
+                last_visit: None,
+                new_obstacle: false,
+            }),
+            '^' => Some(Self {
+                square_type: SquareType::Guard,
+                direction: Direction::Up,
+                last_visit: None,
+                new_obstacle: false,
+            }),
+            'O' => Some(Self {
+                square_type: SquareType::Obstacle,
+                direction: Direction::None,
+                last_visit: None,
+                new_obstacle: true,
+            }),
+            _ => None,
+        }
+    }
+
+    fn rotate(&mut self) {
+        match self.direction {
+            Direction::Up => self.direction = Direction::Right,
+            Direction::Down => self.direction = Direction::Left,
+            Direction::Left => self.direction = Direction::Up,
+            Direction::Right => self.direction = Direction::Down,
+            Direction::None => panic!("Invalid direction"),
+        }
+    }
+}
+
+impl Display for Square {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.square_type {
+            SquareType::Standard => {
+                if self.last_visit.is_some() {
+                    write!(f, "x")
+                } else {
+                    write!(f, ".")
+                }
+            }
+            SquareType::Obstacle => {
+                if self.new_obstacle {
+                    write!(f, "O")
+                } else {
+                    write!(f, "#")
+                }
+            }
+            SquareType::Guard => match self.direction {
+                Direction::Up => write!(f, "^"),
+                Direction::Down => write!(f, "v"),
+                Direction::Left => write!(f, "<"),
+                Direction::Right => write!(f, ">"),
+                Direction::None => panic!("Invalid direction"),
+            },
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Map {
+    map: Grid<Square>,
+    guard: Point,
+}
+
+enum MapState {
+    Loop,
+    OutOfBounds,
+    Continue,
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.map)
+    }
+}
+
+impl Map {
+    fn from_string(data: &str) -> Result<Self, ParseError> {
+        let map = Grid::parse(data, Square::from_char)?;
+        let guard = map
+            .position(|sq| sq.square_type == SquareType::Guard)
+            .ok_or_else(|| ParseError::at(data, &data[data.len()..], "map has no guard `^`"))?;
+
+        Ok(Self { map, guard })
+    }
+
+    fn place_obstacle(&mut self, p: Point) {
+        self.map[p].square_type = SquareType::Obstacle;
+        self.map[p].new_obstacle = true;
+    }
+
+    fn visited(&self) -> usize {
+        self.map
+            .values()
+            .filter(|sq| sq.last_visit.is_some())
+            .count()
+            + 1
+    }
+
+    fn update(&mut self) -> MapState {
+        let guard = self.guard;
+        let guard_direction = &self.map[guard].direction.clone();
+
+        let new_guard = guard + guard_direction.step();
+
+        // complete condition, out of bounds
+        let Some(next_square) = self.map.get(new_guard) else {
+            return MapState::OutOfBounds;
+        };
+
+        if next_square.square_type == SquareType::Obstacle {
+            self.map[guard].rotate();
+            return MapState::Continue;
+        }
+
+        self.map[guard].square_type = SquareType::Standard;
+        match self.map[guard].visit(guard_direction) {
+            MapState::Loop => return MapState::Loop,
+            MapState::OutOfBounds => return MapState::OutOfBounds,
+            MapState::Continue => {
+                self.map[new_guard].square_type = SquareType::Guard;
+                self.map[new_guard].direction = guard_direction.clone();
+
+                self.guard = new_guard;
+            }
+        }
+        MapState::Continue
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Parsed = Map;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(Map::from_string(input)?)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Result<Answer> {
+        let mut map = parsed.clone();
+        loop {
+            match map.update() {
+                MapState::Loop => break,
+                MapState::OutOfBounds => break,
+                MapState::Continue => {}
+            }
+        }
+        Ok(map.visited().into())
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Result<Answer> {
+        let mut vec_seeds = vec![];
+        for (p, sq) in parsed.map.iter() {
+            if sq.square_type == SquareType::Standard {
+                let mut new_seed = parsed.clone();
+                new_seed.place_obstacle(p);
+                vec_seeds.push(new_seed);
+            }
+        }
+
+        let mut looping_seed_count = 0;
+        for mut map in vec_seeds {
+            loop {
+                match map.update() {
+                    MapState::Loop => {
+                        looping_seed_count += 1;
+                        break;
+                    }
+                    MapState::OutOfBounds => break,
+                    MapState::Continue => {}
+                }
+            }
+        }
+
+        Ok(looping_seed_count.into())
+    }
+}
@@ -0,0 +1,165 @@
+use anyhow::Result;
+use common::{parse_number, Answer, ParseError, Solution};
+use std::fmt::{Debug, Display};
+
+struct OpResult {
+    result: usize,
+}
+
+impl OpResult {
+    fn from_string(line: &str, s: &str) -> Result<OpResult, ParseError> {
+        let result = parse_number(line, s)?;
+        Ok(OpResult { result })
+    }
+}
+
+#[derive(Clone)]
+struct OpComponent {
+    value: usize,
+}
+
+impl OpComponent {
+    fn from_string(line: &str, s: &str) -> Result<OpComponent, ParseError> {
+        let value = parse_number(line, s)?;
+        Ok(OpComponent { value })
+    }
+}
+
+impl Display for OpComponent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+impl Debug for OpComponent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+pub struct Operation {
+    result: OpResult,
+    numbers: Vec<OpComponent>,
+}
+
+static OPERATORS: [char; 2] = ['+', '*'];
+static OPERATORS_WITH_CONCAT: [char; 3] = ['+', '*', '|'];
+
+impl Operation {
+    fn from_string(s: &str) -> Result<Operation, ParseError> {
+        let (result, numbers) = s
+            .split_once(":")
+            .ok_or_else(|| ParseError::at(s, &s[s.len()..], "expected `:` after the test value"))?;
+        let result = OpResult::from_string(s, result)?;
+
+        let numbers: Vec<OpComponent> = numbers
+            .split(" ")
+            .filter(|x| !x.is_empty())
+            .map(|x| OpComponent::from_string(s, x))
+            .collect::<Result<Vec<OpComponent>, _>>()?;
+
+        if numbers.is_empty() {
+            return Err(ParseError::at(
+                s,
+                &s[s.len()..],
+                "expected at least one number",
+            ));
+        }
+
+        Ok(Operation { result, numbers })
+    }
+
+    fn combine(&self, comp1: &OpComponent, comp2: &OpComponent) -> Result<usize> {
+        Ok((comp1.to_string() + &comp2.to_string()).parse::<usize>()?)
+    }
+
+    fn recursive_calc(
+        &self,
+        operators: &[char],
+        component_numbers: &[OpComponent],
+        idx: usize,
+        result: usize,
+    ) -> bool {
+        if idx == component_numbers.len() {
+            return result == self.result.result;
+        }
+
+        let num = component_numbers[idx].value;
+
+        for op in operators.iter() {
+            match op {
+                '+' => {
+                    match self.recursive_calc(operators, component_numbers, idx + 1, result + num) {
+                        true => return true,
+                        false => continue,
+                    }
+                }
+                '*' => {
+                    let mut result = result;
+                    if result == 0 {
+                        result = 1;
+                    }
+                    match self.recursive_calc(operators, component_numbers, idx + 1, result * num) {
+                        true => return true,
+                        false => continue,
+                    }
+                }
+                '|' => {
+                    let new_num = self
+                        .combine(&OpComponent { value: result }, &component_numbers[idx])
+                        .unwrap();
+
+                    // start a new search with the new list
+                    match self.recursive_calc(operators, component_numbers, idx + 1, new_num) {
+                        true => return true,
+                        false => continue,
+                    }
+                }
+                _ => {
+                    panic!("Invalid operator");
+                }
+            }
+        }
+        false
+    }
+
+    fn is_valid(&self, operators: &[char]) -> bool {
+        self.recursive_calc(operators, &self.numbers, 0, 0)
+    }
+}
+
+impl Display for Operation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Operation: {} = {:?}", self.result.result, self.numbers)
+    }
+}
+
+fn total_valid(operations: &[Operation], operators: &[char]) -> usize {
+    operations
+        .iter()
+        .filter(|op| op.is_valid(operators))
+        .map(|op| op.result.result)
+        .sum()
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Parsed = Vec<Operation>;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        let operations = input
+            .lines()
+            .enumerate()
+            .map(|(idx, line)| Operation::from_string(line).map_err(|e| e.offset_lines(idx)))
+            .collect::<Result<_, _>>()?;
+        Ok(operations)
+    }
+
+    fn part1(operations: &Self::Parsed) -> Result<Answer> {
+        Ok(total_valid(operations, &OPERATORS).into())
+    }
+
+    fn part2(operations: &Self::Parsed) -> Result<Answer> {
+        Ok(total_valid(operations, &OPERATORS_WITH_CONCAT).into())
+    }
+}
@@ -0,0 +1,146 @@
+use anyhow::Result;
+use common::{Answer, ParseError, Solution};
+use grid::{Grid, Point};
+use std::fmt::Display;
+
+#[derive(Clone, PartialEq, Debug)]
+enum CellType {
+    Blank,
+    Antenna,
+}
+
+#[derive(Clone, Debug)]
+struct Cell {
+    cell_type: CellType,
+    identifier: Option<char>,
+    is_antinode: bool,
+}
+
+impl Cell {
+    fn from_character(character: char) -> Option<Cell> {
+        match character {
+            '.' => Some(Cell {
+                cell_type: CellType::Blank,
+                identifier: None,
+                is_antinode: false,
+            }),
+            c if c.is_alphanumeric() => Some(Cell {
+                cell_type: CellType::Antenna,
+                identifier: Some(c),
+                is_antinode: false,
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.cell_type {
+            CellType::Blank if self.is_antinode => write!(f, "#"),
+            CellType::Blank => write!(f, "."),
+            CellType::Antenna => write!(f, "{}", self.identifier.unwrap()),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Map {
+    grid: Grid<Cell>,
+}
+
+impl Map {
+    fn from_string(data: &str) -> Result<Map, ParseError> {
+        Ok(Map {
+            grid: Grid::parse(data, Cell::from_character)?,
+        })
+    }
+
+    fn get_antennas(&self) -> Vec<(Point, char)> {
+        self.grid
+            .iter()
+            .filter_map(|(p, c)| c.identifier.map(|ident| (p, ident)))
+            .collect()
+    }
+
+    fn set_antinode(&mut self, p: Point) -> bool {
+        match self.grid.get_mut(p) {
+            Some(cell) => {
+                cell.is_antinode = true;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn set_antinode_ray(&mut self, start: Point, diff: Point) {
+        for p in self.grid.ray(start, diff) {
+            self.set_antinode(p);
+        }
+    }
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.grid)
+    }
+}
+
+fn count_antinodes(map: &Map, resonant: bool) -> usize {
+    let mut m = map.clone();
+
+    let antennas = m.get_antennas();
+    let mut identifiers = Vec::new();
+
+    for (_, ident) in &antennas {
+        if !identifiers.contains(ident) {
+            identifiers.push(*ident);
+        }
+    }
+
+    for identifier in &identifiers {
+        let antennas = antennas
+            .iter()
+            .filter(|(_, ident)| ident == identifier)
+            .map(|(p, _)| *p)
+            .collect::<Vec<Point>>();
+
+        for (idx, &this_antenna) in antennas.iter().enumerate() {
+            let mut antennas_without = antennas.clone();
+            antennas_without.remove(idx);
+
+            for other_antenna in antennas_without {
+                let diff = other_antenna - this_antenna;
+
+                if resonant {
+                    m.set_antinode_ray(this_antenna, diff);
+                    m.set_antinode_ray(this_antenna, -diff);
+                } else {
+                    // the antinode on the far side of the other antenna is
+                    // covered when the pair is visited the other way round
+                    m.set_antinode(this_antenna - diff);
+                }
+            }
+        }
+    }
+
+    m.grid.values().filter(|c| c.is_antinode).count()
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Parsed = Map;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(Map::from_string(input)?)
+    }
+
+    fn part1(map: &Self::Parsed) -> Result<Answer> {
+        Ok(count_antinodes(map, false).into())
+    }
+
+    fn part2(map: &Self::Parsed) -> Result<Answer> {
+        Ok(count_antinodes(map, true).into())
+    }
+}
@@ -1,12 +1,6 @@
 use anyhow::Result;
-use std::{fmt::Display, fs::File, io::Read};
-
-fn retrieve_data() -> Result<String> {
-    let mut f = File::open("C:\\software\\adventofcode24\\day9\\day9.txt")?;
-    let mut content = String::new();
-    f.read_to_string(&mut content)?;
-    Ok(content)
-}
+use common::{Answer, ParseError, Solution};
+use std::fmt::Display;
 
 #[derive(Debug, Clone)]
 struct Block {
@@ -33,7 +27,7 @@ impl Display for Block {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Partition {
     blocks: Vec<Block>,
 }
@@ -73,18 +67,13 @@ impl Partition {
     }
 
     fn contiguous_free_space_for_size(&self, size: usize) -> Option<usize> {
-        for i in 0..self.blocks.len() {
-            if self.blocks[i].is_free() {
-                if self.contiguous_space(i) >= size {
-                    return Some(i);
-                }
-            }
-        }
-        None
+        (0..self.blocks.len())
+            .find(|&i| self.blocks[i].is_free() && self.contiguous_space(i) >= size)
     }
 
     fn take_block(&mut self) -> Option<Block> {
-        for i in 0..self.blocks.len() {
+        // take from the back so the blocks left behind stay at the front
+        for i in (0..self.blocks.len()).rev() {
             if !self.blocks[i].is_free() {
                 let ret = self.blocks[i].clone();
                 self.blocks[i].id = -1;
@@ -113,24 +102,33 @@ impl Display for Partition {
     }
 }
 
-struct Disk {
+#[derive(Clone)]
+pub struct Disk {
     partitions: Vec<Partition>,
 }
 
 impl Disk {
-    fn from_string(representation: String) -> Self {
+    fn from_string(input: &str) -> Result<Self, ParseError> {
         let mut partitions = Vec::new();
 
         let mut is_file = true;
         let mut idx = 0;
 
-        for c in representation.bytes() {
+        let representation = input.trim();
+        if representation.is_empty() {
+            return Err(ParseError::at(input, representation, "expected a disk map"));
+        }
+        for (pos, c) in representation.char_indices() {
             let file_idx = if is_file { Some(idx) } else { None };
 
-            partitions.push(Partition::new(
-                file_idx,
-                (c as char).to_string().parse::<usize>().unwrap(),
-            ));
+            let size = c.to_digit(10).ok_or_else(|| {
+                ParseError::at(
+                    input,
+                    &representation[pos..pos + c.len_utf8()],
+                    "expected a single digit size",
+                )
+            })?;
+            partitions.push(Partition::new(file_idx, size as usize));
 
             if is_file {
                 idx += 1;
@@ -139,7 +137,7 @@ impl Disk {
             is_file = !is_file;
         }
 
-        Disk { partitions }
+        Ok(Disk { partitions })
     }
 
     fn find_free_block(&self) -> Option<usize> {
@@ -178,12 +176,7 @@ impl Disk {
     }
 
     fn compress_as_blocks(&mut self) {
-        loop {
-            let last_used_partition_idx = match self.last_used_partition() {
-                Some(idx) => idx,
-                None => break,
-            };
-
+        while let Some(last_used_partition_idx) = self.last_used_partition() {
             let free_block_idx = match self.find_free_block() {
                 Some(idx) => idx,
                 None => break,
@@ -199,7 +192,8 @@ impl Disk {
 
     fn last_used_file(&self) -> Option<usize> {
         for (idx, partition) in self.partitions.iter().enumerate().rev() {
-            if partition.used() == partition.size() {
+            // a zero-size partition is trivially full but holds no file
+            if partition.size() > 0 && partition.used() == partition.size() {
                 return Some(idx);
             }
         }
@@ -208,7 +202,7 @@ impl Disk {
 
     fn file_by_id(&self, id: i32) -> Option<usize> {
         for (idx, partition) in self.partitions.iter().enumerate() {
-            if partition.blocks.len() == 0 {
+            if partition.blocks.is_empty() {
                 continue;
             }
             if partition.blocks.iter().all(|b| b.id == id) {
@@ -228,14 +222,19 @@ impl Disk {
     }
 
     fn compress_as_partitions(&mut self) {
-        let initial_partition = self.last_used_file().unwrap();
-        let last_file_partition = &self.partitions[initial_partition];
-        let mut last_block_id = last_file_partition.blocks[0].id;
+        let Some(initial_partition) = self.last_used_file() else {
+            return;
+        };
+        let mut last_block_id = self.partitions[initial_partition].blocks[0].id;
 
         while last_block_id > -1 {
             let last_used_partition_idx = match self.file_by_id(last_block_id) {
                 Some(idx) => idx,
-                None => break,
+                // a zero-size file has nothing to move
+                None => {
+                    last_block_id -= 1;
+                    continue;
+                }
             };
 
             let free_block_idx = match self.find_free_partition_for_size(
@@ -277,12 +276,54 @@ impl Display for Disk {
     }
 }
 
-fn main() {
-    let data = retrieve_data().unwrap();
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Parsed = Disk;
+
+    fn parse(input: &str) -> Result<Self::Parsed> {
+        Ok(Disk::from_string(input)?)
+    }
+
+    fn part1(disk: &Self::Parsed) -> Result<Answer> {
+        let mut disk = disk.clone();
+        disk.compress_as_blocks();
+        Ok(disk.checksum_blocks().into())
+    }
+
+    fn part2(disk: &Self::Parsed) -> Result<Answer> {
+        let mut disk = disk.clone();
+        disk.compress_as_partitions();
+        Ok(disk.checksum_blocks().into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn answers(input: &str) -> (Answer, Answer) {
+        let disk = Day9::parse(input).unwrap();
+        (Day9::part1(&disk).unwrap(), Day9::part2(&disk).unwrap())
+    }
+
+    #[test]
+    fn test_empty_disk_map() {
+        let err = Day9::parse(" \n").err().unwrap();
+        assert_eq!(
+            err.downcast_ref::<ParseError>().unwrap().message(),
+            "expected a disk map"
+        );
+    }
 
-    let mut disk = Disk::from_string(data);
-    println!("{}", disk);
-    disk.compress_as_partitions();
-    println!("{}", disk);
-    println!("{}", disk.checksum_blocks());
+    #[test]
+    fn test_zero_size_partitions() {
+        assert_eq!(answers("0"), (Answer::from(0usize), Answer::from(0usize)));
+        assert_eq!(answers("10"), (Answer::from(0usize), Answer::from(0usize)));
+        // file 1 is empty, and file 2 is too big for either gap in part 2
+        assert_eq!(
+            answers("11012"),
+            (Answer::from(6usize), Answer::from(14usize))
+        );
+    }
 }
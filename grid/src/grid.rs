@@ -0,0 +1,363 @@
+use crate::Point;
+use common::ParseError;
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+
+/// A rectangular grid stored row by row. Every lookup takes a signed
+/// `Point`, so stepping off an edge gives `None` rather than wrapping or
+/// panicking.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Point) -> T) -> Grid<T> {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point::new(x as i32, y as i32)))
+            .map(&mut f)
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Builds a grid from lines of text, mapping each character with `f`.
+    /// A character `f` rejects, or a line of a different length to the
+    /// first, is reported with its line and column.
+    pub fn parse(text: &str, mut f: impl FnMut(char) -> Option<T>) -> Result<Grid<T>, ParseError> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for line in text.trim_end_matches(['\n', '\r']).lines() {
+            let mut line_width = 0;
+            for (idx, c) in line.char_indices() {
+                if width == Some(line_width) {
+                    return Err(ParseError::at(
+                        text,
+                        &line[idx..],
+                        format!(
+                            "line is longer than the first line's {} characters",
+                            line_width
+                        ),
+                    ));
+                }
+                let cell = f(c).ok_or_else(|| {
+                    ParseError::at(text, &line[idx..idx + c.len_utf8()], "invalid character")
+                })?;
+                cells.push(cell);
+                line_width += 1;
+            }
+
+            match width {
+                None => width = Some(line_width),
+                Some(width) if width != line_width => {
+                    return Err(ParseError::at(
+                        text,
+                        &line[line.len()..],
+                        format!("line is shorter than the first line's {} characters", width),
+                    ))
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Grid {
+                width,
+                height,
+                cells,
+            }),
+            _ => Err(ParseError::at(text, &text[..0], "grid is empty")),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.x >= 0
+            && point.y >= 0
+            && (point.x as usize) < self.width
+            && (point.y as usize) < self.height
+    }
+
+    /// Position of `point` in the row-major cell order, if it is on the grid.
+    pub fn index_of(&self, point: Point) -> Option<usize> {
+        if self.contains(point) {
+            Some(point.y as usize * self.width + point.x as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn point_of(&self, index: usize) -> Point {
+        Point::new((index % self.width) as i32, (index / self.width) as i32)
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index_of(point).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index_of(point).map(|index| &mut self.cells[index])
+    }
+
+    /// Every point on the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.cells.len())
+            .map(move |index| Point::new((index % width) as i32, (index / width) as i32))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point, &mut T)> {
+        self.points().zip(self.cells.iter_mut())
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// The first point, row by row, whose cell matches `predicate`.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.cells
+            .iter()
+            .position(&mut predicate)
+            .map(|index| self.point_of(index))
+    }
+
+    /// The up to four on-grid points sharing an edge with `point`.
+    pub fn neighbours(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.steps_from(point, &Point::ORTHOGONAL)
+    }
+
+    /// The up to eight on-grid points sharing an edge or a corner with `point`.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.steps_from(point, &Point::ALL)
+    }
+
+    fn steps_from<'a>(
+        &'a self,
+        point: Point,
+        steps: &'a [Point],
+    ) -> impl Iterator<Item = Point> + 'a {
+        steps
+            .iter()
+            .map(move |&step| point + step)
+            .filter(|&next| self.contains(next))
+    }
+
+    /// Points from `start` onwards, moving by `step` each time, until the
+    /// edge of the grid. The ray does not borrow the grid, so cells can be
+    /// updated while walking it.
+    pub fn ray(&self, start: Point, step: Point) -> Ray {
+        Ray {
+            next: start,
+            step,
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    /// Every top-left to bottom-right diagonal, starting from the bottom-left
+    /// corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = Ray> + '_ {
+        let left = (1..self.height).rev().map(|y| Point::new(0, y as i32));
+        let top = (0..self.width).map(|x| Point::new(x as i32, 0));
+        left.chain(top)
+            .map(move |start| self.ray(start, Point::DOWN_RIGHT))
+    }
+
+    /// Every top-right to bottom-left diagonal, starting from the top-left
+    /// corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = Ray> + '_ {
+        let top = (0..self.width).map(|x| Point::new(x as i32, 0));
+        let right = (1..self.height).map(|y| Point::new(self.width as i32 - 1, y as i32));
+        top.chain(right)
+            .map(move |start| self.ray(start, Point::DOWN_LEFT))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{} is outside the grid", point))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{} is outside the grid", point))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Iterator returned by [`Grid::ray`].
+#[derive(Debug, Clone)]
+pub struct Ray {
+    next: Point,
+    step: Point,
+    width: usize,
+    height: usize,
+}
+
+impl Iterator for Ray {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Point> {
+        let point = self.next;
+        let on_grid = point.x >= 0
+            && point.y >= 0
+            && (point.x as usize) < self.width
+            && (point.y as usize) < self.height;
+        if !on_grid {
+            return None;
+        }
+        self.next += self.step;
+        if self.step == Point::default() {
+            // a zero step would otherwise repeat the same point forever
+            self.next = Point::new(-1, -1);
+        }
+        Some(point)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn letters() -> Grid<char> {
+        Grid::parse("abc\ndef\n", Some).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = letters();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[Point::new(2, 1)], 'f');
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+
+        let digits = Grid::parse("12\r\n34\r\n", |c| c.to_digit(10)).unwrap();
+        assert_eq!(digits.values().sum::<u32>(), 10);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = Grid::parse("12\n3x\n", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((err.line(), err.column(), err.text()), (2, 2, "x"));
+
+        // a lone carriage return is reported rather than silently kept
+        let err = Grid::parse("12\r\n3\r4\r\n", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((err.line(), err.column(), err.text()), (2, 2, "\r"));
+
+        let err = Grid::parse("abc\nde\n", Some).unwrap_err();
+        assert_eq!((err.line(), err.column(), err.text()), (2, 3, ""));
+
+        let err = Grid::parse("ab\nabcd\n", Some).unwrap_err();
+        assert_eq!((err.line(), err.column(), err.text()), (2, 3, "cd"));
+
+        assert!(Grid::parse("", Some).is_err());
+    }
+
+    #[test]
+    fn test_bounds() {
+        let mut grid = letters();
+        assert_eq!(grid.get(Point::new(-1, 0)), None);
+        assert_eq!(grid.get(Point::new(0, 2)), None);
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        *grid.get_mut(Point::new(1, 0)).unwrap() = 'B';
+        assert_eq!(grid.row(0), ['a', 'B', 'c']);
+        assert_eq!(grid.position(|&c| c == 'e'), Some(Point::new(1, 1)));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = letters();
+        let corner: Vec<char> = grid.neighbours(Point::new(0, 0)).map(|p| grid[p]).collect();
+        assert_eq!(corner, ['b', 'd']);
+        let middle: Vec<char> = grid
+            .neighbours8(Point::new(1, 0))
+            .map(|p| grid[p])
+            .collect();
+        assert_eq!(middle, ['c', 'f', 'e', 'd', 'a']);
+    }
+
+    #[test]
+    fn test_lines() {
+        let grid = letters();
+        let ray: String = grid
+            .ray(Point::new(2, 1), Point::LEFT)
+            .map(|p| grid[p])
+            .collect();
+        assert_eq!(ray, "fed");
+
+        let columns: Vec<String> = grid.columns().map(|c| c.collect()).collect();
+        assert_eq!(columns, ["ad", "be", "cf"]);
+
+        let diagonals: Vec<String> = grid
+            .diagonals()
+            .map(|d| d.map(|p| grid[p]).collect())
+            .collect();
+        assert_eq!(diagonals, ["d", "ae", "bf", "c"]);
+
+        let anti_diagonals: Vec<String> = grid
+            .anti_diagonals()
+            .map(|d| d.map(|p| grid[p]).collect())
+            .collect();
+        assert_eq!(anti_diagonals, ["a", "bd", "ce", "f"]);
+    }
+}
@@ -0,0 +1,5 @@
+mod grid;
+mod point;
+
+pub use grid::{Grid, Ray};
+pub use point::Point;
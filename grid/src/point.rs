@@ -0,0 +1,127 @@
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A signed grid coordinate, or a step between two coordinates. `y` grows
+/// downwards, matching the order rows appear in the puzzle text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point {
+    pub const UP: Point = Point::new(0, -1);
+    pub const DOWN: Point = Point::new(0, 1);
+    pub const LEFT: Point = Point::new(-1, 0);
+    pub const RIGHT: Point = Point::new(1, 0);
+    pub const UP_LEFT: Point = Point::new(-1, -1);
+    pub const UP_RIGHT: Point = Point::new(1, -1);
+    pub const DOWN_LEFT: Point = Point::new(-1, 1);
+    pub const DOWN_RIGHT: Point = Point::new(1, 1);
+
+    pub const ORTHOGONAL: [Point; 4] = [Point::UP, Point::RIGHT, Point::DOWN, Point::LEFT];
+    pub const DIAGONAL: [Point; 4] = [
+        Point::UP_RIGHT,
+        Point::DOWN_RIGHT,
+        Point::DOWN_LEFT,
+        Point::UP_LEFT,
+    ];
+    pub const ALL: [Point; 8] = [
+        Point::UP,
+        Point::UP_RIGHT,
+        Point::RIGHT,
+        Point::DOWN_RIGHT,
+        Point::DOWN,
+        Point::DOWN_LEFT,
+        Point::LEFT,
+        Point::UP_LEFT,
+    ];
+
+    pub const fn new(x: i32, y: i32) -> Point {
+        Point { x, y }
+    }
+
+    /// Quarter turn clockwise, so `UP` becomes `RIGHT`.
+    pub fn rotate_right(self) -> Point {
+        Point::new(-self.y, self.x)
+    }
+
+    /// Quarter turn anticlockwise, so `UP` becomes `LEFT`.
+    pub fn rotate_left(self) -> Point {
+        Point::new(self.y, -self.x)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<i32> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: i32) -> Point {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let p = Point::new(3, 4);
+        assert_eq!(p + Point::RIGHT, Point::new(4, 4));
+        assert_eq!(p - Point::new(1, 1), Point::new(2, 3));
+        assert_eq!(Point::DOWN_LEFT * 3, Point::new(-3, 3));
+        assert_eq!(-p, Point::new(-3, -4));
+    }
+
+    #[test]
+    fn test_rotation() {
+        assert_eq!(Point::UP.rotate_right(), Point::RIGHT);
+        assert_eq!(Point::RIGHT.rotate_right(), Point::DOWN);
+        assert_eq!(Point::UP.rotate_left(), Point::LEFT);
+        for step in Point::ALL {
+            assert_eq!(step.rotate_right().rotate_left(), step);
+        }
+    }
+}